@@ -0,0 +1,208 @@
+use std::fmt;
+
+/// The value a solver produces for one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Screen(Screen),
+    /// The input has no answer, such as when there's no way through a maze.
+    NotFound,
+}
+
+impl Answer {
+    /// Whether the answer spans more than one line once rendered.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Screen(_))
+    }
+
+    /// The answer as typed into the puzzle page, if it can be.
+    ///
+    /// Screens have to be read by a human, unless they only hold letters
+    /// [`Screen::decode`] knows.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Number(n) => Some(n.to_string()),
+            Answer::Text(s) => Some(s.clone()),
+            Answer::Screen(s) => s.decode(),
+            Answer::NotFound => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Screen(s) => write!(f, "{s}"),
+            Answer::NotFound => write!(f, "(no answer)"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<Screen> for Answer {
+    fn from(s: Screen) -> Self {
+        Answer::Screen(s)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::NotFound, Into::into)
+    }
+}
+
+/// A monochrome pixel display, such as the CRT of day 10.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screen {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Screen {
+    pub fn new(width: usize, pixels: Vec<bool>) -> Self {
+        assert_eq!(pixels.len() % width, 0, "ragged screen");
+        Self { width, pixels }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    pub fn pixels(&self) -> &[bool] {
+        &self.pixels
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[bool]> {
+        self.pixels.chunks(self.width)
+    }
+
+    /// The text on the screen, if it's all letters of the font puzzles draw
+    /// with.
+    pub fn decode(&self) -> Option<String> {
+        if self.height() != GLYPH_HEIGHT || !self.width.is_multiple_of(GLYPH_STRIDE) {
+            return None;
+        }
+
+        (0..self.width / GLYPH_STRIDE)
+            .map(|i| {
+                let glyph = self
+                    .rows()
+                    .map(|row| &row[i * GLYPH_STRIDE..][..GLYPH_STRIDE - 1])
+                    .collect::<Vec<_>>();
+                FONT.iter()
+                    .find(|(_, rows)| {
+                        glyph.iter().zip(rows).all(|(row, font)| {
+                            row.iter().zip(font.bytes()).all(|(&p, f)| p == (f == b'#'))
+                        })
+                    })
+                    .map(|&(letter, _)| letter)
+            })
+            .collect()
+    }
+}
+
+const GLYPH_HEIGHT: usize = 6;
+/// Width of a letter along with the blank column after it.
+const GLYPH_STRIDE: usize = 5;
+
+/// The letters known to show up on puzzle screens.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for &pixel in row {
+                write!(f, "{}", if pixel { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[&str]) -> Screen {
+        let pixels = rows.concat().bytes().map(|b| b == b'#').collect();
+        Screen::new(rows[0].len(), pixels)
+    }
+
+    #[test]
+    fn test_decode() {
+        let hi = screen(&[
+            "#..#..##.",
+            "#..#...#.",
+            "####...#.",
+            "#..#...#.",
+            "#..##..#.",
+            "#..#..##.",
+        ]);
+        assert_eq!(hi.decode(), None);
+
+        let hello = screen(&[
+            "#..#.####.#....#.....##..",
+            "#..#.#....#....#....#..#.",
+            "####.###..#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.#....#....#....#..#.",
+            "#..#.####.####.####..##..",
+        ]);
+        assert_eq!(hello.decode().as_deref(), Some("HELLO"));
+        assert_eq!(Answer::from(hello).submission().as_deref(), Some("HELLO"));
+        assert_eq!(screen(&["#...."]).decode(), None);
+    }
+
+    #[test]
+    fn test_from_option() {
+        assert_eq!(Answer::from(Some(42)), Answer::Number(42));
+        assert_eq!(Answer::from(None::<i64>), Answer::NotFound);
+        assert_eq!(Answer::NotFound.submission(), None);
+    }
+}
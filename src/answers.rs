@@ -0,0 +1,193 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Error, Report};
+
+/// An answer as written in `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Recorded {
+    Number(i64),
+    /// Text answers, and screens drawn as `#` and `.` lines.
+    Text(String),
+}
+
+impl From<&Answer> for Recorded {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(n) => Recorded::Number(*n),
+            Answer::Text(s) => Recorded::Text(s.clone()),
+            Answer::Screen(s) => Recorded::Text(s.to_string()),
+            Answer::NotFound => Recorded::Text(answer.to_string()),
+        }
+    }
+}
+
+impl Recorded {
+    /// Whether `answer` is the one recorded, ignoring the trailing newlines
+    /// multi-line TOML strings tend to pick up.
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match (self, Recorded::from(answer)) {
+            (Recorded::Number(a), Recorded::Number(b)) => *a == b,
+            (Recorded::Text(a), Recorded::Text(b)) => a.trim_end() == b.trim_end(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recorded::Number(n) => write!(f, "{n}"),
+            Recorded::Text(s) => write!(f, "{}", s.trim_end()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<Recorded>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<Recorded>,
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Match,
+    Mismatch(Recorded),
+    /// Nothing was recorded for the part yet.
+    New,
+}
+
+/// Known-good answers for each day, kept in `answers.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers(BTreeMap<String, DayAnswers>);
+
+impl KnownAnswers {
+    pub const FILE: &'static str = "answers.toml";
+
+    /// Reads the answers at `path`, or starts from none if there is no file.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = toml::to_string(self).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, text).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    fn key(day: u8) -> String {
+        format!("day{day:02}")
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Recorded> {
+        let answers = self.0.get(&Self::key(day))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &Answer) {
+        let answers = self.0.entry(Self::key(day)).or_default();
+        let slot = match part {
+            1 => &mut answers.part1,
+            2 => &mut answers.part2,
+            _ => panic!("there is no part {part}"),
+        };
+        *slot = Some(answer.into());
+    }
+
+    pub fn status(&self, day: u8, part: u8, answer: &Answer) -> Status {
+        match self.get(day, part) {
+            None => Status::New,
+            Some(recorded) if recorded.matches(answer) => Status::Match,
+            Some(recorded) => Status::Mismatch(recorded.clone()),
+        }
+    }
+
+    /// Compares every part of `report` to the recorded answers.
+    pub fn check(&self, report: &Report) -> Vec<Status> {
+        report
+            .parts
+            .iter()
+            .map(|p| self.status(report.day, p.part, &p.answer))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Screen;
+
+    const ANSWERS: &str = r#"
+[day01]
+part1 = 24000
+part2 = 45000
+
+[day05]
+part1 = "CMZ"
+
+[day10]
+part2 = """
+#.#.
+.#.#
+"""
+"#;
+
+    #[test]
+    fn test_status() {
+        let answers: KnownAnswers = toml::from_str(ANSWERS).unwrap();
+        let screen = Screen::new(4, vec![true, false, true, false, false, true, false, true]);
+
+        assert_eq!(answers.status(1, 1, &Answer::Number(24000)), Status::Match);
+        assert_eq!(
+            answers.status(1, 2, &Answer::Number(45001)),
+            Status::Mismatch(Recorded::Number(45000))
+        );
+        assert_eq!(
+            answers.status(5, 1, &"CMZ".to_string().into()),
+            Status::Match
+        );
+        assert_eq!(answers.status(5, 2, &"MCD".to_string().into()), Status::New);
+        assert_eq!(answers.status(10, 2, &screen.into()), Status::Match);
+        assert_eq!(answers.status(11, 1, &Answer::Number(1)), Status::New);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = KnownAnswers::default();
+        answers.set(5, 1, &"CMZ".to_string().into());
+        answers.set(15, 2, &Answer::Number(56000011));
+        answers.set(
+            10,
+            2,
+            &Screen::new(2, vec![true, false, false, true]).into(),
+        );
+
+        let text = toml::to_string(&answers).unwrap();
+        assert_eq!(toml::from_str::<KnownAnswers>(&text).unwrap(), answers);
+    }
+}
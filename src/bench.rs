@@ -0,0 +1,256 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, ParseError, Runner};
+
+/// A timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Summary of the timings of one phase over repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            n if n % 2 == 1 => samples[n / 2],
+            n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        })
+    }
+}
+
+/// Timings of a day over repeated runs on the same input.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+impl Measurement {
+    pub fn phase(&self, phase: Phase) -> Option<&Stats> {
+        self.phases
+            .iter()
+            .find(|(p, _)| *p == phase)
+            .map(|(_, s)| s)
+    }
+}
+
+/// Solves `input` with `runner` once to warm up, then `runs` more times,
+/// timing parsing and each part separately.
+pub fn measure(runner: &dyn Runner, input: &str, runs: usize) -> Result<Measurement, ParseError> {
+    runner.run(input, None)?;
+
+    let mut samples = BTreeMap::<Phase, Vec<Duration>>::new();
+    for _ in 0..runs {
+        let report = runner.run(input, None)?;
+        samples
+            .entry(Phase::Parse)
+            .or_default()
+            .push(report.parse_time);
+        for part in &report.parts {
+            let phase = if part.part == 1 {
+                Phase::Part1
+            } else {
+                Phase::Part2
+            };
+            samples.entry(phase).or_default().push(part.time);
+        }
+    }
+
+    Ok(Measurement {
+        day: runner.day(),
+        runs,
+        phases: samples
+            .into_iter()
+            .filter_map(|(phase, samples)| Some((phase, Stats::new(&samples)?)))
+            .collect(),
+    })
+}
+
+/// Median timings of earlier benchmarks, kept in `bench.toml` so that later
+/// runs can be compared against them.
+///
+/// Baselines are kept separately for each day and each input, since the
+/// sample and the real input take wildly different times. Callers name the
+/// inputs, e.g. by their [`input_hash`](crate::record::input_hash), so that
+/// timings of one input are never compared with those of another.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baselines(BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>);
+
+impl Baselines {
+    pub const FILE: &'static str = "bench.toml";
+
+    /// Reads the baselines at `path`, or starts from none if there is no file.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = toml::to_string(self).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, text).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    fn key(day: u8) -> String {
+        format!("day{day:02}")
+    }
+
+    /// Median time of `phase` last recorded for `day` on the input named
+    /// `input`.
+    pub fn get(&self, day: u8, input: &str, phase: Phase) -> Option<Duration> {
+        let nanos = self.0.get(&Self::key(day))?.get(input)?.get(phase.key())?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    pub fn set(&mut self, input: &str, measurement: &Measurement) {
+        let phases = self
+            .0
+            .entry(Self::key(measurement.day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default();
+        for (phase, stats) in &measurement.phases {
+            phases.insert(phase.key().to_string(), stats.median.as_nanos() as u64);
+        }
+    }
+
+    /// Relative change of the median of `phase` against the baseline, e.g.
+    /// `0.25` for 25% slower.
+    pub fn change(&self, input: &str, measurement: &Measurement, phase: Phase) -> Option<f64> {
+        let baseline = self.get(measurement.day, input, phase)?.as_secs_f64();
+        let median = measurement.phase(phase)?.median.as_secs_f64();
+        (baseline > 0.).then(|| median / baseline - 1.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::Day01;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::new(&[]), None);
+        assert_eq!(
+            Stats::new(&[ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: ms(3)
+            })
+        );
+        assert_eq!(
+            Stats::new(&[ms(4), ms(1), ms(3), ms(12)]),
+            Some(Stats {
+                min: ms(1),
+                median: Duration::from_micros(3500),
+                mean: ms(5)
+            })
+        );
+    }
+
+    #[test]
+    fn test_measure() {
+        let m = measure(&Day01, Day01.sample(), 3).unwrap();
+        assert_eq!(m.day, 1);
+        assert_eq!(m.runs, 3);
+        assert_eq!(
+            m.phases.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+            Phase::ALL
+        );
+    }
+
+    #[test]
+    fn test_baselines() {
+        let stats = |median| Stats {
+            min: median,
+            median,
+            mean: median,
+        };
+        let measurement = |parse, part1| Measurement {
+            day: 1,
+            runs: 1,
+            phases: vec![(Phase::Parse, stats(parse)), (Phase::Part1, stats(part1))],
+        };
+
+        let mut baselines = Baselines::default();
+        let before = measurement(ms(2), ms(4));
+        assert_eq!(baselines.change("sample", &before, Phase::Parse), None);
+
+        baselines.set("sample", &before);
+        let text = toml::to_string(&baselines).unwrap();
+        let baselines = toml::from_str::<Baselines>(&text).unwrap();
+        assert_eq!(baselines.get(1, "sample", Phase::Part1), Some(ms(4)));
+        assert_eq!(baselines.get(1, "real", Phase::Part1), None);
+
+        let after = measurement(ms(3), ms(2));
+        assert_eq!(baselines.change("sample", &after, Phase::Parse), Some(0.5));
+        assert_eq!(baselines.change("sample", &after, Phase::Part1), Some(-0.5));
+        assert_eq!(baselines.change("sample", &after, Phase::Part2), None);
+    }
+}
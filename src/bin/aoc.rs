@@ -0,0 +1,938 @@
+use std::{
+    error::Error,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use adventofcode2022::{
+    answers::{KnownAnswers, Status},
+    bench::{self, Baselines, Phase},
+    client::{Client, Fetched, Verdict},
+    days::{
+        self,
+        day02::{Day02, Game},
+        day03::Day03,
+        day15::Day15,
+    },
+    http::UreqClient,
+    ledger::Ledger,
+    logging::{self, Filter},
+    record::{self, Record},
+    render::{self, Palette},
+    scaffold, viz, Answer, Config, InputSource, Report, Runner, Solution,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Which log events to print on stderr, like `info` or `warn,11=trace`.
+    /// Defaults to `$AOC_LOG`, or `warn`.
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<Filter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for one day, or for every day.
+    Run(RunArgs),
+    /// Download puzzle inputs into the inputs directory.
+    Fetch(FetchArgs),
+    /// Solve one part of a day and submit the answer.
+    Submit(SubmitArgs),
+    /// Run every day with an input and check the answers against the recorded
+    /// ones.
+    Verify(VerifyArgs),
+    /// Time parsing and each part of one day, or of every day, over repeated
+    /// runs.
+    Bench(BenchArgs),
+    /// Add a new day to the crate `aoc` was built from, using the template in
+    /// `src/days`.
+    New(NewArgs),
+    /// Print a random input for a day, to try the solver on inputs larger
+    /// than the real ones.
+    Gen(GenArgs),
+    /// Print the highest scoring day 2 strategy guide for the opponent's
+    /// shapes in the input, with what stands out about them on stderr.
+    Optimize(OptimizeArgs),
+    /// Print the items packed in both compartments of each day 3 rucksack
+    /// and the badge of each group, flagging anything unexpected.
+    Rucksacks(RucksacksArgs),
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the puzzle input from this file, or from stdin if `-`.
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Directory holding the `dayNN.txt` inputs. Defaults to `$AOC_INPUTS`,
+    /// or `inputs/`.
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run. Defaults to the latest implemented day.
+    day: Option<u8>,
+
+    /// Only solve this part of the puzzle.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// Run every implemented day and print a table of the answers.
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Rules of the game on day 2, instead of Rock Paper Scissors. See
+    /// `aoc optimize --help`.
+    #[arg(long, conflicts_with_all = ["all", "group_size", "row", "max"])]
+    rules: Option<PathBuf>,
+
+    /// Number of elves in each group on day 3, instead of 3.
+    #[arg(long, conflicts_with_all = ["all", "row", "max"])]
+    group_size: Option<usize>,
+
+    /// Row to scan for beacon-free positions on day 15, instead of 2000000.
+    #[arg(long, conflicts_with = "all", allow_negative_numbers = true)]
+    row: Option<i64>,
+
+    /// Largest coordinate of the distress beacon on day 15, instead of
+    /// 4000000.
+    #[arg(long, conflicts_with = "all", allow_negative_numbers = true)]
+    max: Option<i64>,
+
+    #[command(flatten)]
+    viz: VizArgs,
+}
+
+#[derive(Args)]
+struct VizArgs {
+    /// Show what the solutions are doing, for the days that can.
+    #[arg(long, value_enum)]
+    viz: Option<VizBackend>,
+
+    /// Where to write the frames: a directory with `--viz dump` or `--viz
+    /// png`, a file with `--viz gif`. Defaults to `frames` or `frames.gif`.
+    #[arg(long, requires = "viz")]
+    viz_out: Option<PathBuf>,
+
+    /// Milliseconds each step of an animation stays up with `--viz ansi` or
+    /// `--viz gif`.
+    #[arg(long, default_value_t = 50, requires = "viz")]
+    viz_delay: u64,
+
+    /// Only show one in this many steps of animations.
+    #[arg(long, default_value_t = 1, requires = "viz")]
+    viz_every: usize,
+
+    /// Pixels per side of each cell with `--viz png` or `--viz gif`.
+    #[arg(long, default_value_t = 4, requires = "viz")]
+    viz_scale: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// For people to read.
+    Text,
+    /// An array with an object per day and part.
+    Json,
+    /// A header, then a line per day and part.
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum VizBackend {
+    /// Colours in the terminal, with animations drawn in place.
+    Ansi,
+    /// Plain text on stdout.
+    Plain,
+    /// One text file per frame.
+    Dump,
+    /// One PNG image per frame.
+    Png,
+    /// A single animated GIF.
+    Gif,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Days to download.
+    #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+
+    /// Download again even if the input is already on disk.
+    #[arg(long)]
+    force: bool,
+
+    /// Directory to save the `dayNN.txt` inputs into. Defaults to
+    /// `$AOC_INPUTS`, or `inputs/`.
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// File recording every submission.
+    #[arg(long, default_value = Ledger::FILE)]
+    ledger: PathBuf,
+
+    /// File recording the answers known to be right.
+    #[arg(long, default_value = KnownAnswers::FILE)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Directory holding the `dayNN.txt` inputs. Defaults to `$AOC_INPUTS`,
+    /// or `inputs/`.
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+
+    /// File recording the answers known to be right.
+    #[arg(long, default_value = KnownAnswers::FILE)]
+    answers: PathBuf,
+
+    /// Record the answers of parts that have none yet.
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark. Defaults to every day with an input.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Number of timed runs, after one untimed warm-up run.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Benchmark on the example from the puzzle text instead of the real
+    /// input.
+    #[arg(long, conflicts_with_all = ["input", "inputs_dir"])]
+    sample: bool,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// File keeping the timings to compare against.
+    #[arg(long, default_value = Baselines::FILE)]
+    baseline: PathBuf,
+
+    /// Don't replace the baseline with the timings of this run.
+    #[arg(long)]
+    no_save: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Saved copy of the puzzle page, to take the sample from its first
+    /// `<pre><code>` block.
+    #[arg(long)]
+    page: Option<PathBuf>,
+
+    /// Directory to create the empty `dayNN.txt` input in. Defaults to
+    /// `$AOC_INPUTS`, or `inputs/`.
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    day: u8,
+
+    /// How large an input to make. What it counts depends on the day, like
+    /// lines of input or the side of a grid.
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+
+    /// Seed for the generator; the same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+#[derive(Args)]
+struct OptimizeArgs {
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// File with the rules of the game instead of Rock Paper Scissors, a line
+    /// for each shape naming the shapes it beats, like `Rock: Scissors,
+    /// Lizard`. Shapes are worth their line number, and written from `A` by
+    /// the opponent and up to `Z` by the player.
+    #[arg(long)]
+    rules: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct RucksacksArgs {
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// Number of elves in each group.
+    #[arg(long, default_value_t = 3)]
+    group_size: usize,
+}
+
+/// Changes in median time smaller than this are put down to noise.
+const BENCH_NOISE: f64 = 0.1;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.log.map_or_else(Filter::from_env, Ok) {
+        Ok(filter) => logging::init(filter),
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match cli.command {
+        Command::Run(args) => args.viz.install().and_then(|()| {
+            let result = if args.all { run_all(&args) } else { run(args) };
+            viz::finish();
+            result
+        }),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+        Command::Gen(args) => generate(args),
+        Command::Optimize(args) => optimize(args),
+        Command::Rucksacks(args) => rucksacks(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match (&self.input, &self.inputs_dir) {
+            (Some(path), _) => InputSource::from_arg(path),
+            (None, Some(dir)) => InputSource::Dir(dir.clone()),
+            (None, None) => InputSource::from_env(),
+        }
+    }
+}
+
+impl VizArgs {
+    fn install(&self) -> Result<(), Box<dyn Error>> {
+        let Some(backend) = self.viz else {
+            return Ok(());
+        };
+        let delay = Duration::from_millis(self.viz_delay);
+        let out = match (&self.viz_out, backend) {
+            (Some(out), _) => out.clone(),
+            (None, VizBackend::Gif) => PathBuf::from("frames.gif"),
+            (None, _) => PathBuf::from("frames"),
+        };
+        let cant_create = |e| format!("can't create {}: {e}", out.display());
+
+        match backend {
+            VizBackend::Ansi => viz::set(viz::Ansi::new(Box::new(io::stdout()), delay)),
+            VizBackend::Plain => viz::set(viz::Plain::new(Box::new(io::stdout()))),
+            VizBackend::Dump => viz::set(viz::Dump::new(&out).map_err(cant_create)?),
+            VizBackend::Png => viz::set(
+                render::Png::new(&out, Palette::default(), self.viz_scale).map_err(cant_create)?,
+            ),
+            VizBackend::Gif => viz::set(
+                render::Gif::new(&out, Palette::default(), self.viz_scale, delay)
+                    .map_err(cant_create)?,
+            ),
+        }
+        viz::set_every(self.viz_every);
+        Ok(())
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), Box<dyn Error>> {
+    let day = match args.day {
+        Some(day) => day,
+        None => days::all().last().map(|r| r.day()).unwrap(),
+    };
+    let runner: Box<dyn Runner> = match (&args.rules, args.group_size, args.row.or(args.max)) {
+        (Some(rules), _, _) if day == Day02::DAY => Box::new(day02(Some(rules))?),
+        (Some(_), _, _) => return Err("--rules only applies to day 2".into()),
+        (_, Some(size), _) if day == Day03::DAY => Box::new(day03(size)?),
+        (_, Some(_), _) => return Err("--group-size only applies to day 3".into()),
+        (_, _, Some(_)) if day == Day15::DAY => Box::new(day15(
+            args.row.unwrap_or(Day15::ROW),
+            args.max.unwrap_or(Day15::MAX),
+        )?),
+        (_, _, Some(_)) => return Err("--row and --max only apply to day 15".into()),
+        (None, None, None) => {
+            days::get(day).ok_or_else(|| format!("day {day} is not implemented"))?
+        }
+    };
+
+    let input = args.inputs.source().load(day)?;
+    let report = runner.run(&input, args.part)?;
+    if args.format != Format::Text {
+        return print_records(args.format, &Record::from_report(&report, &input));
+    }
+
+    println!(
+        "Day {day:02} (parsed in {})",
+        fmt_duration(report.parse_time)
+    );
+    for part in &report.parts {
+        let time = fmt_duration(part.time);
+        if part.answer.is_multiline() {
+            println!("  Part {} ({time}):\n{}", part.part, part.answer);
+        } else {
+            println!("  Part {} ({time}): {}", part.part, part.answer);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_all(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let source = args.inputs.source();
+    let mut reports = Vec::new();
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+
+    for runner in days::all() {
+        let day = runner.day();
+        match source.load(day) {
+            Ok(input) => match runner.run(&input, args.part) {
+                Ok(report) => {
+                    records.extend(Record::from_report(&report, &input));
+                    reports.push(Outcome::Solved(report));
+                }
+                Err(e) => {
+                    reports.push(Outcome::BadInput(day));
+                    errors.push(e);
+                }
+            },
+            Err(adventofcode2022::Error::MissingInput { .. }) => {
+                reports.push(Outcome::NoInput(day))
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if args.format == Format::Text {
+        print_reports(&reports);
+    } else {
+        print_records(args.format, &records)?;
+    }
+
+    // Tell about the broken inputs only once the other days are out.
+    for e in &errors {
+        eprintln!("error: {e}");
+    }
+    match errors.len() {
+        0 => Ok(()),
+        n => Err(format!("{n} of the inputs could not be parsed").into()),
+    }
+}
+
+/// What became of a day in `aoc run --all`.
+enum Outcome {
+    Solved(Report),
+    NoInput(u8),
+    BadInput(u8),
+}
+
+/// Prints `records` on stdout in a format for scripts.
+fn print_records(format: Format, records: &[Record]) -> Result<(), Box<dyn Error>> {
+    let out = io::stdout().lock();
+    match format {
+        Format::Json => record::write_json(out, records)?,
+        Format::Csv => record::write_csv(out, records)?,
+        Format::Text => unreachable!("text is printed as a report or a table"),
+    }
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), Box<dyn Error>> {
+    let dir = args.inputs_dir.unwrap_or_else(InputSource::default_dir);
+    let client = Client::new(UreqClient::new(), Config::load()?.session()?);
+
+    for day in args.days {
+        match client.fetch_input(day, &dir, SystemTime::now(), args.force)? {
+            Fetched::Cached(path) => println!("Day {day:02}: already in {}", path.display()),
+            Fetched::Downloaded(path) => println!("Day {day:02}: saved to {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), Box<dyn Error>> {
+    let (day, part) = (args.day, args.part);
+    let runner = days::get(day).ok_or_else(|| format!("day {day} is not implemented"))?;
+
+    let input = args.inputs.source().load(day)?;
+    let report = runner.run(&input, Some(part))?;
+    let answer = &report.parts[0].answer;
+    if *answer == Answer::NotFound {
+        return Err(format!("day {day} part {part} has no answer for this input").into());
+    }
+    let answer = answer
+        .submission()
+        .ok_or_else(|| format!("the answer has to be read and submitted by hand:\n{answer}"))?;
+
+    let client = Client::new(UreqClient::new(), Config::load()?.session()?);
+    let mut ledger = Ledger::load(&args.ledger)?;
+
+    println!("Submitting {answer} for day {day:02} part {part}");
+    let judgement = ledger.submit(&client, day, part, &answer, SystemTime::now());
+    ledger.save(&args.ledger)?;
+
+    let judgement = judgement?;
+    let verdict = match judgement.verdict {
+        Verdict::Right => "that's the right answer!",
+        Verdict::Wrong => "that's not the right answer",
+        Verdict::TooHigh => "that's not the right answer: too high",
+        Verdict::TooLow => "that's not the right answer: too low",
+        Verdict::TooRecent => "answer not checked: the last one was too recent",
+        Verdict::WrongLevel => "answer not checked: the part is locked or already solved",
+    };
+    println!("{verdict}");
+    if let Some(wait) = judgement.wait {
+        println!("Wait {}s before trying again", wait.as_secs());
+    }
+
+    if judgement.verdict == Verdict::Right {
+        let mut answers = KnownAnswers::load(&args.answers)?;
+        answers.set(day, part, &report.parts[0].answer);
+        answers.save(&args.answers)?;
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), Box<dyn Error>> {
+    let source = match args.inputs_dir {
+        Some(dir) => InputSource::Dir(dir),
+        None => InputSource::from_env(),
+    };
+    let mut answers = KnownAnswers::load(&args.answers)?;
+
+    let mut rows = Vec::new();
+    let mut mismatches = Vec::new();
+    let (mut ok, mut new, mut skipped) = (0, 0, 0);
+
+    for runner in days::all() {
+        let day = runner.day();
+        let input = match source.load(day) {
+            Ok(input) => input,
+            Err(adventofcode2022::Error::MissingInput { .. }) => {
+                skipped += 1;
+                rows.push([
+                    format!("{day:02}"),
+                    "-".to_string(),
+                    "-".to_string(),
+                    "skipped (no input)".to_string(),
+                    "-".to_string(),
+                ]);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let report = runner.run(&input, None)?;
+        for (part, status) in report.parts.iter().zip(answers.check(&report)) {
+            let status = match status {
+                Status::Match => {
+                    ok += 1;
+                    "ok".to_string()
+                }
+                Status::New => {
+                    new += 1;
+                    if args.record {
+                        answers.set(day, part.part, &part.answer);
+                        "new (recorded)".to_string()
+                    } else {
+                        "new".to_string()
+                    }
+                }
+                Status::Mismatch(expected) => {
+                    mismatches.push((day, part.part, part.answer.clone(), expected));
+                    "MISMATCH".to_string()
+                }
+            };
+            let answer = if part.answer.is_multiline() {
+                "(see below)".to_string()
+            } else {
+                part.answer.to_string()
+            };
+            rows.push([
+                format!("{day:02}"),
+                part.part.to_string(),
+                answer,
+                status,
+                fmt_duration(part.time),
+            ]);
+        }
+    }
+
+    print_table(
+        [
+            ("Day", Align::Left),
+            ("Part", Align::Left),
+            ("Answer", Align::Left),
+            ("Status", Align::Left),
+            ("Time", Align::Right),
+        ],
+        &rows,
+    );
+
+    for (day, part, answer, expected) in &mismatches {
+        println!(
+            "\nDay {day:02} part {part}:\n  expected: {}\n  got:      {}",
+            expected.to_string().replace('\n', "\n            "),
+            answer.to_string().replace('\n', "\n            "),
+        );
+    }
+
+    println!(
+        "\n{ok} ok, {} mismatched, {new} new, {skipped} days skipped",
+        mismatches.len()
+    );
+
+    if args.record && new > 0 {
+        answers.save(&args.answers)?;
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} mismatched", mismatches.len()).into())
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), Box<dyn Error>> {
+    let runners = match args.day {
+        Some(day) => vec![days::get(day).ok_or_else(|| format!("day {day} is not implemented"))?],
+        None => days::all(),
+    };
+    if args.day.is_none() && args.inputs.input.is_some() {
+        return Err("--input needs a day to benchmark".into());
+    }
+    let source = args.inputs.source();
+    let inputs = match &args.inputs.input {
+        _ if args.sample => "the sample inputs".to_string(),
+        Some(path) => path.display().to_string(),
+        None => "the real inputs".to_string(),
+    };
+    let mut baselines = Baselines::load(&args.baseline)?;
+
+    let mut rows = Vec::new();
+    for runner in runners {
+        let day = runner.day();
+        // The example of day 15 is much smaller than the real input.
+        let runner: Box<dyn Runner> = match day {
+            Day15::DAY if args.sample => Box::new(day15(10, 20)?),
+            _ => runner,
+        };
+        let input = if args.sample {
+            runner.sample().to_string()
+        } else {
+            match source.load(day) {
+                Ok(input) => input,
+                // Skip days without an input unless that's the one asked for.
+                Err(adventofcode2022::Error::MissingInput { .. }) if args.day.is_none() => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        // Baselines are keyed by the input itself, so timing another input
+        // never replaces the timings of the usual one.
+        let input_key = record::input_hash(&input);
+        let measurement = bench::measure(runner.as_ref(), &input, args.runs as usize)?;
+        for phase in Phase::ALL {
+            let Some(stats) = measurement.phase(phase) else {
+                continue;
+            };
+            let change = match baselines.change(&input_key, &measurement, phase) {
+                Some(c) if c > BENCH_NOISE => format!("{:+.1}% slower", c * 100.),
+                Some(c) if c < -BENCH_NOISE => format!("{:+.1}% faster", c * 100.),
+                Some(c) => format!("{:+.1}%", c * 100.),
+                None => "-".to_string(),
+            };
+            rows.push([
+                format!("{day:02}"),
+                phase.to_string(),
+                fmt_duration(stats.min),
+                fmt_duration(stats.median),
+                fmt_duration(stats.mean),
+                change,
+            ]);
+        }
+        baselines.set(&input_key, &measurement);
+    }
+
+    println!("{} runs on {inputs}\n", args.runs);
+    print_table(
+        [
+            ("Day", Align::Left),
+            ("Phase", Align::Left),
+            ("Min", Align::Right),
+            ("Median", Align::Right),
+            ("Mean", Align::Right),
+            ("Change", Align::Left),
+        ],
+        &rows,
+    );
+
+    if !args.no_save {
+        baselines.save(&args.baseline)?;
+    }
+
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<(), Box<dyn Error>> {
+    let sample = match &args.page {
+        Some(page) => {
+            let html =
+                std::fs::read_to_string(page).map_err(|e| format!("{}: {e}", page.display()))?;
+            let sample = scaffold::extract_sample(&html)
+                .ok_or_else(|| format!("{}: no <pre><code> block", page.display()))?;
+            Some(sample)
+        }
+        None => None,
+    };
+    let inputs = args.inputs_dir.unwrap_or_else(InputSource::default_dir);
+
+    // The new day goes into the sources this binary was built from, wherever
+    // it runs from.
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let created = scaffold::create(root, &inputs, args.day, sample.as_deref())?;
+
+    println!("Created {}", created.module.display());
+    if let Some(input) = created.input {
+        println!("Created {}", input.display());
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenArgs) -> Result<(), Box<dyn Error>> {
+    let day = args.day;
+    let runner = days::get(day).ok_or_else(|| format!("day {day} is not implemented"))?;
+    let input = runner
+        .generate(args.size, args.seed)
+        .ok_or_else(|| format!("day {day} has no input generator"))?;
+
+    let mut out = io::stdout().lock();
+    out.write_all(input.as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+fn optimize(args: OptimizeArgs) -> Result<(), Box<dyn Error>> {
+    let day = day02(args.rules.as_deref())?;
+    let input = args.inputs.source().load(Day02::DAY)?;
+    let rounds = day.parse(&input)?;
+    let plan = day.plan(&rounds);
+    let pattern = &plan.pattern;
+    let game = day.game();
+
+    eprintln!(
+        "Best score: {} (following the guide: {})",
+        plan.score,
+        Answer::from(day.part1(&rounds))
+    );
+    let total = pattern.frequencies.iter().sum::<usize>().max(1);
+    let shares = game
+        .shapes()
+        .zip(&pattern.frequencies)
+        .map(|(shape, &n)| {
+            format!(
+                "{} {:.1}%",
+                game.name(shape),
+                100.0 * n as f64 / total as f64
+            )
+        })
+        .collect::<Vec<_>>();
+    eprintln!("Opponent plays: {}", shares.join(", "));
+    match pattern.period {
+        Some(period) => eprintln!("Opponent repeats every {period} rounds"),
+        None => eprintln!("Opponent doesn't repeat"),
+    }
+    let (shape, score) = pattern.best_fixed;
+    eprintln!("Best single shape: {} for {score}", game.name(shape));
+
+    print!("{plan}");
+    Ok(())
+}
+
+/// Day 2 played by the rules in the file `rules`, or Rock Paper Scissors.
+fn day02(rules: Option<&Path>) -> Result<Day02, Box<dyn Error>> {
+    let game = match rules {
+        Some(path) => {
+            let text =
+                std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+            Game::parse(&text).map_err(|e| format!("{}: {e}", path.display()))?
+        }
+        None => Game::default(),
+    };
+    Day02::with_letters(game).ok_or_else(|| "the rules have more shapes than letters".into())
+}
+
+/// Day 3 with groups of `group_size` elves.
+fn day03(group_size: usize) -> Result<Day03, Box<dyn Error>> {
+    Day03::new(group_size).ok_or_else(|| "groups need at least one elf".into())
+}
+
+/// Day 15 scanning `row`, with the distress beacon somewhere from 0 to `max`.
+fn day15(row: i64, max: i64) -> Result<Day15, Box<dyn Error>> {
+    Day15::new(row, max).ok_or_else(|| "the search area needs a max of at least 0".into())
+}
+
+fn rucksacks(args: RucksacksArgs) -> Result<(), Box<dyn Error>> {
+    let input = args.inputs.source().load(Day03::DAY)?;
+    let day = day03(args.group_size)?;
+    let diagnosis = day.diagnose(&input)?;
+
+    print!("{diagnosis}");
+    eprintln!(
+        "{} rucksacks in {} groups, {} flagged",
+        diagnosis.rucksacks.len(),
+        diagnosis.groups.len(),
+        diagnosis.problems()
+    );
+    Ok(())
+}
+
+/// Prints one row per day; days whose input is missing are passed as `Err`.
+fn print_reports(reports: &[Outcome]) {
+    let cell = |report: &Report, part: u8| match report.part(part) {
+        Some(p) if p.answer.is_multiline() => "(see below)".to_string(),
+        Some(p) => p.answer.to_string(),
+        None => "-".to_string(),
+    };
+
+    let rows = reports
+        .iter()
+        .map(|r| match r {
+            Outcome::Solved(r) => [
+                format!("{:02}", r.day),
+                cell(r, 1),
+                cell(r, 2),
+                fmt_duration(r.total_time()),
+            ],
+            Outcome::NoInput(day) => [
+                format!("{day:02}"),
+                "(no input)".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ],
+            Outcome::BadInput(day) => [
+                format!("{day:02}"),
+                "(invalid input)".to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ],
+        })
+        .collect::<Vec<_>>();
+
+    print_table(
+        [
+            ("Day", Align::Left),
+            ("Part 1", Align::Left),
+            ("Part 2", Align::Left),
+            ("Time", Align::Right),
+        ],
+        &rows,
+    );
+
+    let solved = reports.iter().filter_map(|r| match r {
+        Outcome::Solved(report) => Some(report),
+        _ => None,
+    });
+    for report in solved {
+        for part in report.parts.iter().filter(|p| p.answer.is_multiline()) {
+            println!(
+                "\nDay {:02} part {}:\n{}",
+                report.day, part.part, part.answer
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Right,
+}
+
+/// Prints `rows` under a header and a rule, each column as wide as its
+/// widest cell and two spaces apart.
+fn print_table<const N: usize>(header: [(&str, Align); N], rows: &[[String; N]]) {
+    let names = header.map(|(name, _)| name.to_string());
+    let widths = std::iter::once(&names)
+        .chain(rows)
+        .fold([0; N], |mut widths, row| {
+            for (w, c) in widths.iter_mut().zip(row) {
+                *w = usize::max(*w, c.chars().count());
+            }
+            widths
+        });
+
+    let print_row = |row: &[String; N]| {
+        let cells = row
+            .iter()
+            .zip(header)
+            .zip(widths)
+            .map(|((cell, (_, align)), width)| match align {
+                Align::Left => format!("{cell:<width$}"),
+                Align::Right => format!("{cell:>width$}"),
+            })
+            .collect::<Vec<_>>();
+        println!("{}", cells.join("  ").trim_end());
+    };
+
+    print_row(&names);
+    let gaps = 2 * N.saturating_sub(1);
+    println!("{}", "-".repeat(widths.iter().sum::<usize>() + gaps));
+    rows.iter().for_each(print_row);
+}
+
+fn fmt_duration(d: Duration) -> String {
+    if d.as_secs() > 0 {
+        format!("{:.2}s", d.as_secs_f64())
+    } else if d.as_millis() > 0 {
+        format!("{:.2}ms", d.as_secs_f64() * 1e3)
+    } else {
+        format!("{:.2}µs", d.as_secs_f64() * 1e6)
+    }
+}
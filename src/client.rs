@@ -0,0 +1,358 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{http::HttpClient, Error, InputSource};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+/// Midnight EST of December 1st, when the first puzzle unlocked.
+const FIRST_UNLOCK: u64 = 1669870800;
+
+/// When the puzzle for `day` becomes available.
+pub fn unlock_time(day: u8) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK + (day as u64 - 1) * 86400)
+}
+
+/// Outcome of [`Client::fetch_input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk; nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer wasn't looked at, because the previous one was too recent.
+    TooRecent,
+    /// The part is locked, or was solved already.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the server rejected this very answer.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+/// Response to an answer submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Judgement {
+    pub verdict: Verdict,
+    /// How long the server asks to wait before submitting again.
+    pub wait: Option<Duration>,
+}
+
+impl Judgement {
+    /// Reads the verdict out of the answer page.
+    pub fn parse(body: &str) -> Option<Self> {
+        let verdict = if body.contains("That's the right answer") {
+            Verdict::Right
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            verdict,
+            wait: parse_wait(body),
+        })
+    }
+}
+
+/// Finds either "You have 1m 5s left to wait" or "please wait 5 minutes".
+fn parse_wait(body: &str) -> Option<Duration> {
+    if let Some((_, rest)) = body.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        return left
+            .split_whitespace()
+            .try_fold(Duration::ZERO, |acc, token| {
+                let (n, unit) = token.split_at(token.len().checked_sub(1)?);
+                let n = n.parse::<u64>().ok()?;
+                let secs = match unit {
+                    "h" => n * 3600,
+                    "m" => n * 60,
+                    "s" => n,
+                    _ => return None,
+                };
+                Some(acc + Duration::from_secs(secs))
+            });
+    }
+
+    let lower = body.to_ascii_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let n = match words.next()? {
+        "one" => 1,
+        n => n.parse::<u64>().ok()?,
+    };
+    words
+        .next()
+        .filter(|unit| unit.starts_with("minute"))
+        .map(|_| Duration::from_secs(n * 60))
+}
+
+/// Talks to adventofcode.com on behalf of the logged in user.
+pub struct Client<H> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl<H: HttpClient> Client<H> {
+    pub fn new(http: H, session: String) -> Self {
+        Self::with_base_url(http, session, BASE_URL.to_string())
+    }
+
+    pub fn with_base_url(http: H, session: String, base_url: String) -> Self {
+        Self {
+            http,
+            base_url,
+            session,
+        }
+    }
+
+    /// Downloads the input for `day` into `dir`, unless it's already there.
+    pub fn fetch_input(
+        &self,
+        day: u8,
+        dir: &Path,
+        now: SystemTime,
+        force: bool,
+    ) -> Result<Fetched, Error> {
+        let path = InputSource::day_path(dir, day);
+        if !force && fs::metadata(&path).map(|m| m.len() > 0).unwrap_or(false) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.input(day, now)?;
+
+        fs::create_dir_all(dir).map_err(|source| Error::Io {
+            path: dir.to_owned(),
+            source,
+        })?;
+        fs::write(&path, input).map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Requests the input for `day`, refusing to do so before it unlocks.
+    pub fn input(&self, day: u8, now: SystemTime) -> Result<String, Error> {
+        check_unlocked(day, now)?;
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let response = self.http.get(&url, &self.session)?;
+
+        if response.body.contains("Please log in") {
+            Err(Error::NotLoggedIn)
+        } else if response.status == 404 || response.body.starts_with("404 Not Found") {
+            Err(Error::NotFound { day })
+        } else if response.status != 200 {
+            Err(Error::HttpStatus {
+                status: response.status,
+                body: response.body,
+            })
+        } else {
+            Ok(response.body)
+        }
+    }
+
+    /// Posts `answer` for `part` of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Judgement, Error> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let response = self.http.post_form(
+            &url,
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        if response.body.contains("Please log in") {
+            Err(Error::NotLoggedIn)
+        } else if response.status != 200 {
+            Err(Error::HttpStatus {
+                status: response.status,
+                body: response.body,
+            })
+        } else {
+            Judgement::parse(&response.body).ok_or(Error::UnexpectedResponse(response.body))
+        }
+    }
+}
+
+fn check_unlocked(day: u8, now: SystemTime) -> Result<(), Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::NotFound { day });
+    }
+
+    match unlock_time(day).duration_since(now) {
+        Ok(remaining) if !remaining.is_zero() => Err(Error::Locked { day, remaining }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{mock::MockServer, UreqClient};
+
+    fn client(server: &MockServer) -> Client<UreqClient> {
+        Client::with_base_url(UreqClient::new(), "cafe".to_string(), server.url())
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn after_unlock(day: u8) -> SystemTime {
+        unlock_time(day) + Duration::from_secs(1)
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let server = MockServer::start(vec![(200, "1000\n2000\n".to_string())]);
+        let dir = temp_dir("fetch");
+        let client = client(&server);
+
+        let path = InputSource::day_path(&dir, 1);
+        assert_eq!(
+            client.fetch_input(1, &dir, after_unlock(1), false).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        // The server only answers once: a second request would fail.
+        assert_eq!(
+            client.fetch_input(1, &dir, after_unlock(1), false).unwrap(),
+            Fetched::Cached(path)
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("session=cafe"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_not_logged_in() {
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+        let server = MockServer::start(vec![(400, body.to_string())]);
+        let dir = temp_dir("login");
+
+        assert!(matches!(
+            client(&server).fetch_input(2, &dir, after_unlock(2), false),
+            Err(Error::NotLoggedIn)
+        ));
+        assert!(!InputSource::day_path(&dir, 2).exists());
+    }
+
+    #[test]
+    fn test_not_found() {
+        let server = MockServer::start(vec![(404, "404 Not Found".to_string())]);
+
+        assert!(matches!(
+            client(&server).input(3, after_unlock(3)),
+            Err(Error::NotFound { day: 3 })
+        ));
+    }
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.  \
+        Please wait one minute before trying again. \
+        <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 38s left to wait. \
+        <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn test_judgement() {
+        assert_eq!(
+            Judgement::parse("<p>That's the right answer!  You are one gold star closer.</p>"),
+            Some(Judgement {
+                verdict: Verdict::Right,
+                wait: None
+            })
+        );
+        assert_eq!(
+            Judgement::parse(TOO_HIGH),
+            Some(Judgement {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            Judgement::parse(TOO_RECENT),
+            Some(Judgement {
+                verdict: Verdict::TooRecent,
+                wait: Some(Duration::from_secs(98))
+            })
+        );
+        assert_eq!(
+            Judgement::parse(
+                "That's not the right answer.  Please wait 5 minutes before trying again."
+            ),
+            Some(Judgement {
+                verdict: Verdict::Wrong,
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(Judgement::parse("<html>Something else</html>"), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, TOO_HIGH.to_string())]);
+
+        assert_eq!(
+            client(&server).submit(1, 2, "4000").unwrap().verdict,
+            Verdict::TooHigh
+        );
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2022/day/1/answer "));
+        assert!(request.ends_with("level=2&answer=4000"));
+    }
+
+    #[test]
+    fn test_locked() {
+        let server = MockServer::start(vec![]);
+        let now = unlock_time(25) - Duration::from_secs(90);
+
+        assert!(matches!(
+            client(&server).input(25, now),
+            Err(Error::Locked { day: 25, remaining }) if remaining == Duration::from_secs(90)
+        ));
+        assert!(server.requests().is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use std::{env, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::Error;
+
+/// Local settings, read from `aoc.toml` in the working directory.
+///
+/// The file holds the session cookie, so it must never be committed.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the adventofcode.com `session` cookie.
+    pub session: Option<String>,
+}
+
+impl Config {
+    pub const FILE: &'static str = "aoc.toml";
+    /// Environment variable taking precedence over [`Config::session`].
+    pub const SESSION_VAR: &'static str = "AOC_SESSION";
+
+    /// Reads [`Config::FILE`], falling back to the defaults if it's missing.
+    pub fn load() -> Result<Self, Error> {
+        let path = Path::new(Self::FILE);
+        if path.exists() {
+            Self::from_path(path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    /// The session cookie, from `AOC_SESSION` or the config file.
+    ///
+    /// A leading `session=` is stripped, so the whole cookie can be pasted.
+    pub fn session(&self) -> Result<String, Error> {
+        env::var(Self::SESSION_VAR)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|s| {
+                let s = s.trim();
+                s.strip_prefix("session=").unwrap_or(s).to_string()
+            })
+            .filter(|s| !s.is_empty())
+            .ok_or(Error::MissingSession)
+    }
+}
@@ -0,0 +1,180 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where the sequence `x0, f(x0), f(f(x0)), ...` starts repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first element of the first repetition.
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// Index of the element equal to the one at `n` that comes before the
+    /// end of the first repetition.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Keeps two elements around at a time, at the
+/// cost of computing `f` about three times per element.
+pub fn floyd<T: PartialEq>(x0: T, f: impl Fn(&T) -> T) -> Cycle {
+    let mut tortoise = f(&x0);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&f(&hare));
+    }
+
+    let mut start = 0;
+    let mut tortoise = x0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        len += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// Brent's algorithm. Keeps two elements around at a time like [`floyd`],
+/// but computes `f` less often.
+pub fn brent<T: PartialEq + Clone>(x0: T, f: impl Fn(&T) -> T) -> Cycle {
+    let (mut power, mut len) = (1, 1);
+    let mut tortoise = x0.clone();
+    let mut hare = f(&x0);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = f(&hare);
+        len += 1;
+    }
+
+    let mut start = 0;
+    let mut tortoise = x0.clone();
+    let mut hare = (0..len).fold(x0, |x, _| f(&x));
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// Remembers the `key` of every element until one comes up again. Computes
+/// `f` once per element, but keeps every key around.
+///
+/// Elements with the same key are taken to be equal, so the key can leave
+/// out whatever doesn't affect what comes next, like a running total.
+pub fn hashed<T, K: Hash + Eq>(x0: T, f: impl Fn(&T) -> T, key: impl Fn(&T) -> K) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut x = x0;
+    for i in 0.. {
+        if let Some(start) = seen.insert(key(&x), i) {
+            return Cycle {
+                start,
+                len: i - start,
+            };
+        }
+        x = f(&x);
+    }
+    unreachable!()
+}
+
+/// Runs `step` on `state` `steps` times, and returns `measure` of the state
+/// after that.
+///
+/// Once the `key` of the state repeats, whole cycles are skipped rather than
+/// simulated, assuming `measure` grows by the same amount during each. The
+/// state is left as it was after the steps actually simulated.
+pub fn fast_forward<S, K: Hash + Eq>(
+    state: &mut S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut measure: impl FnMut(&S) -> usize,
+) -> usize {
+    let mut seen = HashMap::new();
+    let mut skipped = None;
+    let mut i = 0;
+
+    while i < steps {
+        if skipped.is_none() {
+            let now = measure(state);
+            if let Some((start, then)) = seen.insert(key(state), (i, now)) {
+                let len = i - start;
+                let cycles = (steps - i) / len;
+                skipped = Some(cycles * (now - then));
+                i += cycles * len;
+                continue;
+            }
+        }
+
+        step(state);
+        i += 1;
+    }
+
+    measure(state) + skipped.unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3, 10, 46, 27, 15, 6, 37, 50, 26, 17, 15, 6, ...
+    fn f(x: &u32) -> u32 {
+        (x * x + 1) % 55
+    }
+
+    #[test]
+    fn test_detect() {
+        let cycle = Cycle { start: 4, len: 6 };
+        assert_eq!(floyd(3, f), cycle);
+        assert_eq!(brent(3, f), cycle);
+        assert_eq!(hashed(3, f, |&x| x), cycle);
+        assert_eq!(floyd(15, f), Cycle { start: 0, len: 6 });
+        assert_eq!(brent(15, f), Cycle { start: 0, len: 6 });
+
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(11), 5);
+        assert_eq!(cycle.reduce(1_000_000_004), 8);
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        // A counter wrapping at 4 that scores its value at every step.
+        let simulate = |steps| {
+            let mut state = (1u32, 0usize);
+            let score = fast_forward(
+                &mut state,
+                steps,
+                |(n, total)| {
+                    *total += *n as usize;
+                    *n = *n % 4 + 1;
+                },
+                |&(n, _)| n,
+                |&(_, total)| total,
+            );
+            (score, state)
+        };
+
+        assert_eq!(simulate(0), (0, (1, 0)));
+        assert_eq!(simulate(6), (13, (3, 13)));
+        assert_eq!(simulate(1_000_000_002).0, 2_500_000_003);
+    }
+}
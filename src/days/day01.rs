@@ -0,0 +1,218 @@
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{rng::Rng, select::top_k, ParseContext, ParseError, Solution};
+use log::debug;
+
+/// Why an inventory couldn't be read.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "couldn't read the inventory: {e}"),
+            ReadError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+/// The calories each elf carries, read from an inventory a line at a time so
+/// that only one line is ever in memory. Blank lines around the inventory
+/// are skipped, and a run of them separates two elves.
+pub struct Inventories<R> {
+    reader: R,
+    line: String,
+    /// Number of the last line read, counting from 1.
+    line_no: usize,
+}
+
+impl<R: BufRead> Inventories<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_no: 0,
+        }
+    }
+
+    /// The next line without its line break, or `None` at the end.
+    fn next_line(&mut self) -> Result<Option<&str>, io::Error> {
+        self.line.clear();
+        if self.reader.read_line(&mut self.line)? == 0 {
+            return Ok(None);
+        }
+        self.line_no += 1;
+        Ok(Some(self.line.trim_end_matches(['\n', '\r'])))
+    }
+}
+
+impl<R: BufRead> Iterator for Inventories<R> {
+    type Item = Result<u64, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut calories = None;
+        loop {
+            let line_no = self.line_no + 1;
+            let line = match self.next_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => return calories.map(Ok),
+                Err(e) => return Some(Err(e.into())),
+            };
+            if line.is_empty() {
+                if calories.is_some() {
+                    return calories.map(Ok);
+                }
+                continue;
+            }
+
+            match ParseContext::new(Day01::DAY, line).number::<u64>(line) {
+                Ok(snack) => *calories.get_or_insert(0) += snack,
+                Err(e) => return Some(Err(ReadError::Parse(ParseError { line: line_no, ..e }))),
+            }
+        }
+    }
+}
+
+/// The `k` elves carrying the most calories, as their position in the
+/// inventory and what they carry, most first.
+pub fn top_elves(reader: impl BufRead, k: usize) -> Result<Vec<(usize, u64)>, ReadError> {
+    itertools::process_results(Inventories::new(reader), |elves| top_k(elves, k))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    /// Calories carried by each elf, in inventory order.
+    type Input<'a> = Vec<u64>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Inventories::new(input.as_bytes())
+            .map(|elf| {
+                elf.map_err(|e| match e {
+                    ReadError::Parse(e) => e,
+                    ReadError::Io(e) => unreachable!("reading from memory failed: {e}"),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, elves: &Self::Input<'_>) -> u64 {
+        top_k(elves.iter().copied(), 1)
+            .first()
+            .map_or(0, |&(_, calories)| calories)
+    }
+
+    fn part2(&self, elves: &Self::Input<'_>) -> u64 {
+        let top = top_k(elves.iter().copied(), 3);
+        debug!("top elves {top:?}");
+        top.iter().map(|&(_, calories)| calories).sum()
+    }
+
+    /// `size` elves, each carrying up to 15 snacks.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let elves = (0..size.max(3))
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| format!("{}\n", rng.range(1000..=60000u64)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        Some(elves.join("\n"))
+    }
+}
+
+const SAMPLE01: &str = r#"
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+"#;
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day01.run1(SAMPLE01), 24000);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day01.run2(SAMPLE01), 45000);
+    }
+
+    #[test]
+    fn test_top_elves() {
+        assert_eq!(
+            top_elves(SAMPLE01.as_bytes(), 3).unwrap(),
+            [(3, 24000), (2, 11000), (4, 10000)]
+        );
+
+        let e = Day01.parse("1000\n\n\n2x00\n").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (1, 4, 1));
+        assert_eq!(e.found, "2x00");
+    }
+
+    /// An inventory made up as it's read, with elf `i` carrying `i % 1000`
+    /// calories.
+    struct Synthetic {
+        elves: usize,
+        next: usize,
+    }
+
+    impl Read for Synthetic {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.next == self.elves {
+                return Ok(0);
+            }
+            let elf = format!("{}\n\n", self.next % 1000);
+            buf[..elf.len()].copy_from_slice(elf.as_bytes());
+            self.next += 1;
+            Ok(elf.len())
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let inventory = BufReader::new(Synthetic {
+            elves: 200_000,
+            next: 0,
+        });
+        assert_eq!(
+            top_elves(inventory, 3).unwrap(),
+            [(999, 999), (1999, 999), (2999, 999)]
+        );
+    }
+}
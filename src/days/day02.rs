@@ -0,0 +1,607 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+
+/// A shape, by its position in the rules of its [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    fn score(self) -> usize {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// A game like Rock Paper Scissors, where each shape beats half of the
+/// others and loses to the other half.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    /// Whether the shape of the row beats the one of the column.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::parse(Self::ROCK_PAPER_SCISSORS).unwrap()
+    }
+}
+
+impl Game {
+    pub const ROCK_PAPER_SCISSORS: &'static str = "Rock: Scissors\nPaper: Rock\nScissors: Paper\n";
+
+    /// Reads rules with a line for each shape, naming it and then the shapes
+    /// it beats, like `Rock: Scissors, Lizard`. Shapes are worth their line
+    /// number.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let ctx = ParseContext::new(Day02::DAY, text).subject("rules");
+        let rules = text
+            .trim()
+            .lines()
+            .map(|line| {
+                let (name, beaten) = line
+                    .split_once(':')
+                    .ok_or_else(|| ctx.error(line, "a rule like `Rock: Scissors`"))?;
+                let beaten = beaten
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|other| !other.is_empty())
+                    .collect::<Vec<_>>();
+                Ok((name.trim(), beaten))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let names = rules.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+        if let Some(i) = (0..names.len()).find(|&i| names[..i].contains(&names[i])) {
+            return Err(ctx.error(names[i], "a shape without a rule yet"));
+        }
+        if names.len() % 2 == 0 {
+            return Err(ctx.error(&text[text.len()..], "an odd number of shapes"));
+        }
+
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (i, (_, beaten)) in rules.iter().enumerate() {
+            for &other in beaten {
+                let j = names
+                    .iter()
+                    .position(|&name| name == other)
+                    .ok_or_else(|| ctx.error(other, "the name of a shape"))?;
+                if i == j || beats[j][i] {
+                    return Err(ctx.error(other, "a shape it doesn't draw or lose to"));
+                }
+                beats[i][j] = true;
+            }
+        }
+        let half = names.len() / 2;
+        if let Some(i) = (0..names.len()).find(|&i| beats[i].iter().filter(|&&b| b).count() != half)
+        {
+            return Err(ctx.error(names[i], format!("a shape beating {half} others")));
+        }
+
+        Ok(Self {
+            names: names.into_iter().map(String::from).collect(),
+            beats,
+        })
+    }
+
+    /// Every shape, from the one worth the least.
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    /// How a round ends for the one playing `player`.
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> Outcome {
+        if player == opponent {
+            Outcome::Draw
+        } else if self.beats[player.0][opponent.0] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// Points for playing `player` against `opponent`: what the shape is worth
+    /// and what the outcome is.
+    pub fn score(&self, player: Shape, opponent: Shape) -> usize {
+        player.0 + 1 + self.outcome(player, opponent).score()
+    }
+
+    /// The shape worth the most of those ending a round against `opponent`
+    /// with `outcome`, if there's any.
+    pub fn respond(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&player| self.outcome(player, opponent) == outcome)
+            .last()
+    }
+
+    /// The shape scoring the most against `opponent`.
+    pub fn best_response(&self, opponent: Shape) -> Shape {
+        self.shapes()
+            .max_by_key(|&player| self.score(player, opponent))
+            .unwrap()
+    }
+}
+
+/// What each symbol of a column of the strategy guide stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbols<T>(HashMap<char, T>);
+
+impl<T: Copy> Symbols<T> {
+    /// The characters of `symbols` standing for `meanings`, in order.
+    pub fn new(symbols: &str, meanings: impl IntoIterator<Item = T>) -> Self {
+        Self(symbols.chars().zip(meanings).collect())
+    }
+
+    pub fn get(&self, symbol: char) -> Option<T> {
+        self.0.get(&symbol).copied()
+    }
+}
+
+impl<T: Copy + PartialEq> Symbols<T> {
+    /// The symbol standing for `meaning`.
+    pub fn symbol(&self, meaning: T) -> Option<char> {
+        self.0.iter().find(|&(_, &m)| m == meaning).map(|(&c, _)| c)
+    }
+}
+
+/// Works out what to play from the second column of the strategy guide.
+pub trait Decoder {
+    fn decode(&self, game: &Game, opponent: Shape, symbol: char) -> Option<Shape>;
+}
+
+/// Takes the second column for the shape to play, like part 1.
+#[derive(Debug, Clone)]
+pub struct AsShape(pub Symbols<Shape>);
+
+impl Decoder for AsShape {
+    fn decode(&self, _: &Game, _: Shape, symbol: char) -> Option<Shape> {
+        self.0.get(symbol)
+    }
+}
+
+/// Takes the second column for how the round needs to end, like part 2, and
+/// plays the shape worth the most that ends it that way.
+#[derive(Debug, Clone)]
+pub struct AsOutcome(pub Symbols<Outcome>);
+
+impl Decoder for AsOutcome {
+    fn decode(&self, game: &Game, opponent: Shape, symbol: char) -> Option<Shape> {
+        game.respond(opponent, self.0.get(symbol)?)
+    }
+}
+
+/// How to read a strategy guide: the game it's for, what the opponent's
+/// symbols stand for, and how to decode the other column.
+#[derive(Debug, Clone)]
+pub struct Strategy<D> {
+    pub game: Game,
+    pub opponent: Symbols<Shape>,
+    pub decoder: D,
+}
+
+impl<D: Decoder> Strategy<D> {
+    /// Total score from following `guide`, or `None` if it has a symbol the
+    /// strategy doesn't know.
+    pub fn score(&self, guide: &[(char, char)]) -> Option<usize> {
+        guide
+            .iter()
+            .map(|&(opponent, symbol)| {
+                let opponent = self.opponent.get(opponent)?;
+                let player = self.decoder.decode(&self.game, opponent, symbol)?;
+                Some(self.game.score(player, opponent))
+            })
+            .sum()
+    }
+}
+
+/// What stands out in the shapes an opponent plays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// Length of the shortest run of shapes the opponent repeats over and
+    /// over, if they go through it at least twice.
+    pub period: Option<usize>,
+    /// How many times each shape is played, from the one worth the least.
+    pub frequencies: Vec<usize>,
+    /// The shape scoring the most when played every round, and its score.
+    pub best_fixed: (Shape, usize),
+}
+
+impl Pattern {
+    pub fn new(game: &Game, opponents: &[Shape]) -> Self {
+        let mut frequencies = vec![0; game.names.len()];
+        for opponent in opponents {
+            frequencies[opponent.0] += 1;
+        }
+        let best_fixed = game
+            .shapes()
+            .map(|player| {
+                let score = game
+                    .shapes()
+                    .map(|opponent| frequencies[opponent.0] * game.score(player, opponent))
+                    .sum();
+                (player, score)
+            })
+            .max_by_key(|&(_, score)| score)
+            .unwrap();
+
+        Self {
+            period: period(opponents),
+            frequencies,
+            best_fixed,
+        }
+    }
+}
+
+/// Length of the shortest block `items` is made of, repeated with the last
+/// copy maybe cut short, if there are at least two whole copies.
+fn period<T: PartialEq>(items: &[T]) -> Option<usize> {
+    // Length of the longest proper prefix of `items[..=i]` that is also a
+    // suffix of it.
+    let mut border = vec![0; items.len()];
+    for i in 1..items.len() {
+        let mut k = border[i - 1];
+        while k > 0 && items[i] != items[k] {
+            k = border[k - 1];
+        }
+        if items[i] == items[k] {
+            k += 1;
+        }
+        border[i] = k;
+    }
+    let period = items.len() - border.last()?;
+    (2 * period <= items.len()).then_some(period)
+}
+
+/// The best way to answer the opponent's shapes of a strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// The guide with the best response to each shape in the second column,
+    /// written as part 1 reads it.
+    pub guide: Vec<(char, char)>,
+    /// The score from following `guide`, the most there is to get.
+    pub score: usize,
+    pub pattern: Pattern,
+}
+
+impl fmt::Display for Plan {
+    /// Writes the guide, a round per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (opponent, player) in &self.guide {
+            writeln!(f, "{opponent} {player}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Strategy guides for a [`Game`], read with what their symbols stand for.
+pub struct Day02 {
+    game: Game,
+    /// The shapes of the first column.
+    opponent: Symbols<Shape>,
+    /// The shapes of the second column, as part 1 reads it.
+    player: Symbols<Shape>,
+    /// The outcomes of the second column, as part 2 reads it.
+    outcomes: Symbols<Outcome>,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Self::with_letters(Game::default()).unwrap()
+    }
+}
+
+impl Day02 {
+    /// Guides for `game`, or `None` if one of the columns has no symbol for
+    /// some shape.
+    pub fn new(
+        game: Game,
+        opponent: Symbols<Shape>,
+        player: Symbols<Shape>,
+        outcomes: Symbols<Outcome>,
+    ) -> Option<Self> {
+        let covered = |symbols: &Symbols<Shape>| game.shapes().all(|s| symbols.symbol(s).is_some());
+        (covered(&opponent) && covered(&player)).then_some(Self {
+            game,
+            opponent,
+            player,
+            outcomes,
+        })
+    }
+
+    /// Guides for `game` writing the opponent's shapes with letters from `A`
+    /// and the player's with letters up to `Z`, like `ABC` and `XYZ` for Rock
+    /// Paper Scissors. `XYZ` stand for a loss, a draw and a win in part 2.
+    /// `None` if there are more shapes than letters.
+    pub fn with_letters(game: Game) -> Option<Self> {
+        const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let count = game.shapes().count();
+        let opponent = Symbols::new(&LETTERS[..count], game.shapes());
+        let player = Symbols::new(
+            LETTERS.get(LETTERS.len().checked_sub(count)?..)?,
+            game.shapes(),
+        );
+        let outcomes = Symbols::new("XYZ", [Outcome::Loss, Outcome::Draw, Outcome::Win]);
+        Self::new(game, opponent, player, outcomes)
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Strategy for reading the second column as the shape to play.
+    fn by_shape(&self) -> Strategy<AsShape> {
+        Strategy {
+            game: self.game.clone(),
+            opponent: self.opponent.clone(),
+            decoder: AsShape(self.player.clone()),
+        }
+    }
+
+    /// The highest scoring answers to the opponent's shapes in `rounds`,
+    /// ignoring the second column.
+    pub fn plan(&self, rounds: &[(char, char)]) -> Plan {
+        let game = &self.game;
+        let opponents = rounds
+            .iter()
+            .map(|&(shape, _)| self.opponent.get(shape).unwrap())
+            .collect::<Vec<_>>();
+
+        let guide = rounds
+            .iter()
+            .zip(&opponents)
+            .map(|(&(shape, _), &opponent)| {
+                let player = game.best_response(opponent);
+                (shape, self.player.symbol(player).unwrap())
+            })
+            .collect();
+        let score = opponents
+            .iter()
+            .map(|&opponent| game.score(game.best_response(opponent), opponent))
+            .sum();
+
+        Plan {
+            guide,
+            score,
+            pattern: Pattern::new(game, &opponents),
+        }
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<(char, char)>;
+    type Output1 = Option<usize>;
+    type Output2 = Option<usize>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        let second = |c| self.player.get(c).is_some() || self.outcomes.get(c).is_some();
+
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let mut chars = line.chars();
+                match (chars.next(), chars.next(), chars.next(), chars.next()) {
+                    (Some(a), Some(' '), Some(b), None)
+                        if self.opponent.get(a).is_some() && second(b) =>
+                    {
+                        Ok((a, b))
+                    }
+                    _ => Err(ctx.error(line, "a round like `A Y`")),
+                }
+            })
+            .collect()
+    }
+
+    /// `None` if the guide uses a symbol that isn't a shape.
+    fn part1(&self, rounds: &Self::Input<'_>) -> Option<usize> {
+        self.by_shape().score(rounds)
+    }
+
+    /// `None` if the guide uses a symbol that isn't an outcome, or asks for
+    /// an outcome no shape gives.
+    fn part2(&self, rounds: &Self::Input<'_>) -> Option<usize> {
+        let strategy = Strategy {
+            game: self.game.clone(),
+            opponent: self.opponent.clone(),
+            decoder: AsOutcome(self.outcomes.clone()),
+        };
+        strategy.score(rounds)
+    }
+
+    /// `size` rounds, with symbols in the second column both parts can
+    /// read.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let symbols = |shapes: &Symbols<Shape>| {
+            self.game
+                .shapes()
+                .filter_map(|shape| shapes.symbol(shape))
+                .collect::<Vec<_>>()
+        };
+        let opponents = symbols(&self.opponent);
+        let players = symbols(&self.player)
+            .into_iter()
+            .filter(|&c| self.outcomes.get(c).is_some())
+            .collect::<Vec<_>>();
+        if players.is_empty() {
+            return None;
+        }
+
+        Some(
+            (0..size)
+                .map(|_| {
+                    let opponent = rng.pick(&opponents);
+                    let player = rng.pick(&players);
+                    format!("{opponent} {player}\n")
+                })
+                .collect(),
+        )
+    }
+}
+
+const SAMPLE01: &str = r#"
+A Y
+B X
+C Z
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day02::default().run1(SAMPLE01), Some(15));
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day02::default().run2(SAMPLE01), Some(12));
+    }
+
+    const RPSLS: &str = r#"
+Rock: Scissors, Lizard
+Paper: Rock, Spock
+Scissors: Paper, Lizard
+Lizard: Spock, Paper
+Spock: Scissors, Rock
+"#;
+
+    #[test]
+    fn test_rules() {
+        let game = Game::parse(RPSLS).unwrap();
+        let shape = |name| game.shape(name).unwrap();
+        assert_eq!(game.outcome(shape("Spock"), shape("Rock")), Outcome::Win);
+        assert_eq!(game.outcome(shape("Spock"), shape("Lizard")), Outcome::Loss);
+        assert_eq!(game.outcome(shape("Paper"), shape("Paper")), Outcome::Draw);
+        assert_eq!(game.score(shape("Lizard"), shape("Paper")), 4 + 6);
+        assert_eq!(
+            game.name(game.respond(shape("Rock"), Outcome::Win).unwrap()),
+            "Spock"
+        );
+        assert_eq!(
+            game.name(game.respond(shape("Rock"), Outcome::Loss).unwrap()),
+            "Lizard"
+        );
+
+        let guide = [('A', 'Z'), ('E', 'V'), ('C', 'X')];
+        let by_shape = Strategy {
+            opponent: Symbols::new("ABCDE", game.shapes()),
+            decoder: AsShape(Symbols::new("VWXYZ", game.shapes())),
+            game: game.clone(),
+        };
+        assert_eq!(by_shape.score(&guide), Some((5 + 6) + 1 + (3 + 3)));
+        assert_eq!(by_shape.score(&[('F', 'V')]), None);
+
+        let by_outcome = Strategy {
+            opponent: Symbols::new("ABCDE", game.shapes()),
+            decoder: AsOutcome(Symbols::new(
+                "XYZ",
+                [Outcome::Loss, Outcome::Draw, Outcome::Win],
+            )),
+            game,
+        };
+        assert_eq!(
+            by_outcome.score(&[('A', 'Z'), ('B', 'X')]),
+            Some((5 + 6) + 5)
+        );
+    }
+
+    #[test]
+    fn test_rules_errors() {
+        let error = |rules: &str| {
+            let e = Game::parse(rules).unwrap_err();
+            (e.line, e.found, e.expected)
+        };
+        assert_eq!(Game::parse(Game::ROCK_PAPER_SCISSORS), Ok(Game::default()));
+        assert_eq!(
+            error("Rock: Scissors\nPaper Rock\nScissors: Paper"),
+            (
+                2,
+                "Paper Rock".into(),
+                "a rule like `Rock: Scissors`".into()
+            )
+        );
+        assert_eq!(
+            error("Rock: Paper\nPaper: Rock"),
+            (2, "".into(), "an odd number of shapes".into())
+        );
+        assert_eq!(
+            error("Rock: Scissors\nPaper: Rock\nScissors: Papyrus"),
+            (3, "Papyrus".into(), "the name of a shape".into())
+        );
+        assert_eq!(
+            error("Rock: Scissors\nPaper: Rock\nScissors: Rock"),
+            (
+                3,
+                "Rock".into(),
+                "a shape it doesn't draw or lose to".into()
+            )
+        );
+        assert_eq!(
+            error("Rock: Scissors, Paper\nPaper:\nScissors: Paper"),
+            (1, "Rock".into(), "a shape beating 1 others".into())
+        );
+        assert_eq!(
+            error("Rock: Scissors\nPaper: Rock\nRock: Paper"),
+            (3, "Rock".into(), "a shape without a rule yet".into())
+        );
+
+        let e = Game::parse("Rock: Scissors\nPaper Rock\nScissors: Paper").unwrap_err();
+        assert!(e.to_string().starts_with("day 2 rules, line 2, column 1:"));
+    }
+
+    #[test]
+    fn test_letters() {
+        let day = Day02::with_letters(Game::parse(RPSLS).unwrap()).unwrap();
+        assert_eq!(day.run1("A Z\nE V\nC X"), Some((5 + 6) + 1 + (3 + 3)));
+        assert_eq!(day.run2("A Z\nB X"), Some((5 + 6) + 5));
+        assert_eq!(day.run2("A V"), None);
+        assert!(day.parse("F V").is_err());
+        assert!(Day02::default().parse("A V").is_err());
+    }
+
+    #[test]
+    fn test_plan() {
+        let day = Day02::default();
+        let rounds = day.parse(SAMPLE01).unwrap();
+        let plan = day.plan(&rounds);
+        assert_eq!(plan.to_string(), "A Y\nB Z\nC X\n");
+        assert_eq!(plan.score, 24);
+        assert_eq!(day.run1(&plan.to_string()), Some(plan.score));
+
+        let game = Game::default();
+        let shape = |name| game.shape(name).unwrap();
+        assert_eq!(plan.pattern.period, None);
+        assert_eq!(plan.pattern.frequencies, [1, 1, 1]);
+        assert_eq!(plan.pattern.best_fixed, (shape("Scissors"), 18));
+
+        let rounds = day.parse("A X\nA Y\nC Z\nA X\nA X\nC Y\nA Z").unwrap();
+        let pattern = day.plan(&rounds).pattern;
+        assert_eq!(pattern.period, Some(3));
+        assert_eq!(pattern.frequencies, [5, 0, 2]);
+        assert_eq!(pattern.best_fixed, (shape("Paper"), 5 * 8 + 2 * 2));
+    }
+}
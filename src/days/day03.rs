@@ -0,0 +1,469 @@
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr},
+};
+
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+
+/// A set of item types, one bit each, from `a` to `z` and then `A` to `Z` in
+/// order of priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const ALL: Self = Self((1 << 52) - 1);
+
+    /// The priority of `item`: 1 to 26 for `a` to `z`, and 27 to 52 for `A`
+    /// to `Z`.
+    pub fn priority(item: char) -> Option<u32> {
+        match item {
+            'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+            'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+            _ => None,
+        }
+    }
+
+    /// The item types in `items`, leaving out anything that isn't one.
+    pub fn new(items: &str) -> Self {
+        Self(
+            items
+                .chars()
+                .filter_map(Self::priority)
+                .fold(0, |set, priority| set | 1 << (priority - 1)),
+        )
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The lowest priority of the items in the set.
+    pub fn min_priority(self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros() + 1)
+    }
+
+    /// The items in the set, in order of priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        ('a'..='z')
+            .chain('A'..='Z')
+            .enumerate()
+            .filter(move |&(i, _)| self.0 & 1 << i != 0)
+            .map(|(_, item)| item)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// The items in each compartment of `rucksack`.
+fn compartments(rucksack: &str) -> (ItemSet, ItemSet) {
+    let (left, right) = rucksack.split_at(rucksack.len() / 2);
+    (ItemSet::new(left), ItemSet::new(right))
+}
+
+/// A random rucksack holding `badge`, with its other items taken from
+/// `pool`. Exactly one item is in both compartments.
+fn rucksack(rng: &mut Rng, pool: &[char], badge: char) -> String {
+    let mut items = pool.to_vec();
+    items.push(badge);
+    rng.shuffle(&mut items);
+    let duplicated = items.pop().unwrap();
+    let (left, right) = items.split_at(items.len() / 2);
+
+    let len = rng.range(3..=16);
+    let mut compartments = [left, right].map(|side| {
+        let mut compartment = vec![duplicated];
+        compartment.extend((1..len).map(|_| *rng.pick(side)));
+        compartment
+    });
+    if duplicated != badge {
+        compartments[usize::from(right.contains(&badge))][1] = badge;
+    }
+    for compartment in &mut compartments {
+        rng.shuffle(compartment);
+    }
+    compartments.concat().into_iter().collect()
+}
+
+/// The items in `set`, with their priorities, or `-` if there are none.
+fn describe(set: ItemSet) -> String {
+    if set.is_empty() {
+        return "-".to_string();
+    }
+    set.items()
+        .map(|item| format!("{item} ({})", ItemSet::priority(item).unwrap()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The lines of `input`, checking that they only hold items.
+fn rucksacks<'a>(ctx: &ParseContext, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| match line.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(ctx.error(&line[i..], "an item from a-z or A-Z")),
+            None => Ok(line),
+        })
+        .collect()
+}
+
+/// What went into both compartments of one rucksack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackReport {
+    pub len: usize,
+    pub duplicates: ItemSet,
+}
+
+impl RucksackReport {
+    /// Whether the rucksack has exactly one duplicated item and splits into
+    /// equal compartments.
+    pub fn is_ok(&self) -> bool {
+        self.duplicates.len() == 1 && self.len.is_multiple_of(2)
+    }
+}
+
+/// What every rucksack in one group has in common.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupReport {
+    /// Index of the group's first rucksack.
+    pub first: usize,
+    pub size: usize,
+    pub badges: ItemSet,
+}
+
+impl GroupReport {
+    pub fn is_ok(&self, group_size: usize) -> bool {
+        self.badges.len() == 1 && self.size == group_size
+    }
+}
+
+/// The duplicated items of every rucksack and the badge of every group,
+/// printed a line each with anything unexpected flagged with `!`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub group_size: usize,
+    pub rucksacks: Vec<RucksackReport>,
+    pub groups: Vec<GroupReport>,
+}
+
+impl Diagnosis {
+    /// Number of rucksacks and groups flagged.
+    pub fn problems(&self) -> usize {
+        self.rucksacks.iter().filter(|r| !r.is_ok()).count()
+            + self
+                .groups
+                .iter()
+                .filter(|g| !g.is_ok(self.group_size))
+                .count()
+    }
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, r) in self.rucksacks.iter().enumerate() {
+            write!(f, "rucksack {}: {}", i + 1, describe(r.duplicates))?;
+            match r.duplicates.len() {
+                0 => write!(f, "  ! no item in both compartments")?,
+                1 => {}
+                n => write!(f, "  ! {n} items in both compartments")?,
+            }
+            if !r.len.is_multiple_of(2) {
+                write!(f, "  ! odd length {}", r.len)?;
+            }
+            writeln!(f)?;
+        }
+
+        for (i, g) in self.groups.iter().enumerate() {
+            write!(
+                f,
+                "group {} (rucksacks {}-{}): {}",
+                i + 1,
+                g.first + 1,
+                g.first + g.size,
+                describe(g.badges)
+            )?;
+            match g.badges.len() {
+                0 => write!(f, "  ! no badge")?,
+                1 => {}
+                n => write!(f, "  ! {n} badges")?,
+            }
+            if g.size != self.group_size {
+                write!(f, "  ! {} of {} rucksacks", g.size, self.group_size)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day03 {
+    /// Number of elves in a group, who all carry its badge.
+    group_size: usize,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Self { group_size: 3 }
+    }
+}
+
+impl Day03 {
+    /// Solves with groups of `group_size` elves, which can't be 0.
+    pub fn new(group_size: usize) -> Option<Self> {
+        (group_size > 0).then_some(Self { group_size })
+    }
+
+    /// Looks at every rucksack and group instead of assuming each has
+    /// exactly one item to report, so only fails on lines that aren't items.
+    pub fn diagnose(&self, input: &str) -> Result<Diagnosis, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        Ok(self.diagnosis(&rucksacks(&ctx, input)?))
+    }
+
+    fn diagnosis(&self, rucksacks: &[&str]) -> Diagnosis {
+        Diagnosis {
+            group_size: self.group_size,
+            rucksacks: rucksacks
+                .iter()
+                .map(|rucksack| {
+                    let (left, right) = compartments(rucksack);
+                    RucksackReport {
+                        len: rucksack.len(),
+                        duplicates: left & right,
+                    }
+                })
+                .collect(),
+            groups: rucksacks
+                .chunks(self.group_size)
+                .enumerate()
+                .map(|(i, group)| GroupReport {
+                    first: i * self.group_size,
+                    size: group.len(),
+                    badges: group
+                        .iter()
+                        .map(|rucksack| ItemSet::new(rucksack))
+                        .fold(ItemSet::ALL, BitAnd::bitand),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<&'a str>;
+    type Output1 = usize;
+    type Output2 = Option<usize>;
+
+    /// Only checks the rucksacks, since part 1 doesn't care how they group.
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        let rucksacks = rucksacks(&ctx, input)?;
+
+        let diagnosis = self.diagnosis(&rucksacks);
+        if let Some(i) = diagnosis.rucksacks.iter().position(|r| !r.is_ok()) {
+            return Err(ctx.error(
+                rucksacks[i],
+                "a rucksack of even length with one item in both compartments",
+            ));
+        }
+
+        Ok(rucksacks)
+    }
+
+    fn part1(&self, rucksacks: &Self::Input<'_>) -> usize {
+        rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (left, right) = compartments(rucksack);
+                (left & right).min_priority().unwrap_or_default() as usize
+            })
+            .sum()
+    }
+
+    /// `None` unless every group is whole and has exactly one badge. `aoc
+    /// rucksacks` tells which ones don't.
+    fn part2(&self, rucksacks: &Self::Input<'_>) -> Option<usize> {
+        rucksacks
+            .chunks(self.group_size)
+            .map(|group| {
+                let badges = group
+                    .iter()
+                    .map(|rucksack| ItemSet::new(rucksack))
+                    .fold(ItemSet::ALL, BitAnd::bitand);
+                if group.len() != self.group_size || badges.len() != 1 {
+                    return None;
+                }
+                badges.min_priority().map(|p| p as usize)
+            })
+            .sum()
+    }
+
+    /// `size` rucksacks, rounded up to a whole number of groups. Each group
+    /// splits the items other than its badge between its rucksacks, so there
+    /// can't be more than 17 of them.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        if !(2..=17).contains(&self.group_size) {
+            return None;
+        }
+
+        let mut input = String::new();
+        for _ in 0..size.div_ceil(self.group_size).max(1) {
+            let mut items = ItemSet::ALL.items().collect::<Vec<_>>();
+            rng.shuffle(&mut items);
+            let badge = items.pop().unwrap();
+            for pool in items
+                .chunks(items.len() / self.group_size)
+                .take(self.group_size)
+            {
+                input += &rucksack(rng, pool, badge);
+                input.push('\n');
+            }
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+"#;
+
+#[cfg(test)]
+const SAMPLE02: &str = r#"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Runner;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day03::default().run1(SAMPLE01), 157);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day03::default().run2(SAMPLE02), Some(70));
+        // Pairs sharing `Z` and `Y`.
+        let pairs = "abZa\ncdZc\nefYe\nghYg\n";
+        assert_eq!(Day03::new(2).unwrap().run2(pairs), Some(52 + 51));
+        assert!(Day03::new(0).is_none());
+
+        let report = Runner::run(&Day03::new(2).unwrap(), pairs, Some(2)).unwrap();
+        assert_eq!(report.parts[0].answer, (52 + 51).into());
+    }
+
+    #[test]
+    fn test_item_set() {
+        let set = ItemSet::new("vJrwpWtwJgWrhcsFMMfFFhFp");
+        assert_eq!(set.len(), 14);
+        assert_eq!(set.min_priority(), Some(3));
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(ItemSet::ALL.items().last(), Some('Z'));
+
+        let (left, right) = compartments("vJrwpWtwJgWrhcsFMMfFFhFp");
+        assert_eq!((left & right).items().collect::<String>(), "p");
+        assert_eq!((left | right), set);
+        assert!((ItemSet::new("abc") & ItemSet::new("ABC")).is_empty());
+        assert_eq!(ItemSet::priority('L'), Some(38));
+        assert_eq!(ItemSet::priority('1'), None);
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let day = Day03::default();
+        let diagnosis = day.diagnose(SAMPLE02).unwrap();
+        assert_eq!(diagnosis.problems(), 0);
+        let report = diagnosis.to_string();
+        assert!(report.starts_with("rucksack 1: p (16)\n"));
+        assert!(report.contains("rucksack 6: s (19)\n"));
+        assert!(report.ends_with("group 2 (rucksacks 4-6): Z (52)\n"));
+
+        let diagnosis = day.diagnose("abcabc\nabxaby\nabcdxyz\nxa\n").unwrap();
+        assert_eq!(diagnosis.rucksacks[0].duplicates, ItemSet::new("abc"));
+        assert_eq!(diagnosis.problems(), 6);
+        let lines = diagnosis.to_string();
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "rucksack 1: a (1), b (2), c (3)  ! 3 items in both compartments"
+        );
+        assert_eq!(
+            lines[1],
+            "rucksack 2: a (1), b (2)  ! 2 items in both compartments"
+        );
+        assert_eq!(
+            lines[2],
+            "rucksack 3: -  ! no item in both compartments  ! odd length 7"
+        );
+        assert_eq!(lines[3], "rucksack 4: -  ! no item in both compartments");
+        assert_eq!(
+            lines[4],
+            "group 1 (rucksacks 1-3): a (1), b (2)  ! 2 badges"
+        );
+        assert_eq!(
+            lines[5],
+            "group 2 (rucksacks 4-4): a (1), x (24)  ! 2 badges  ! 1 of 3 rucksacks"
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let day = Day03::default();
+        let e = day
+            .parse(&SAMPLE01.replace("PmmdzqPrVvPwwTWBwg", "PmmdzqPrVvAwwTWBwg"))
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+        assert_eq!(e.found, "PmmdzqPrVvAwwTWBwg");
+        assert!(day.parse("abcab\n").is_err());
+        assert_eq!(day.diagnose("aa\nbb\ncc\n").unwrap().problems(), 1);
+
+        // Groups only matter to part 2.
+        assert_eq!(day.run1("aa\nbb\ncc\n"), 1 + 2 + 3);
+        assert_eq!(day.run2("aa\nbb\ncc\n"), None);
+        assert_eq!(day.run1("aa\naa\n"), 2);
+        assert_eq!(day.run2("aa\naa\n"), None);
+        assert_eq!(Day03::new(6).unwrap().run2(SAMPLE02), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day03::default()
+            .parse(&SAMPLE01.replace("PmmdzqPrV", "Pmmdzq-rV"))
+            .unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (3, 4, 7));
+        assert_eq!(e.found, "-rVvPwwTWBwg");
+    }
+}
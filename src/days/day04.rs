@@ -0,0 +1,96 @@
+use crate::{interval::Interval, rng::Rng, ParseContext, ParseError, Solution};
+
+type Assignment = Interval<usize>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<(Assignment, Assignment)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        let assignment = |range: &str| -> Result<Assignment, ParseError> {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| ctx.error(range, "a range like `2-4`"))?;
+            Ok(Interval::inclusive(ctx.number(start)?, ctx.number(end)?))
+        };
+
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (a, b) = line
+                    .split_once(',')
+                    .ok_or_else(|| ctx.error(line, "a pair like `2-4,6-8`"))?;
+                Ok((assignment(a)?, assignment(b)?))
+            })
+            .collect()
+    }
+
+    fn part1(&self, pairs: &Self::Input<'_>) -> usize {
+        pairs
+            .iter()
+            .filter(|(a, b)| a.covers(b) || b.covers(a))
+            .count()
+    }
+
+    fn part2(&self, pairs: &Self::Input<'_>) -> usize {
+        pairs.iter().filter(|(a, b)| a.overlaps(b)).count()
+    }
+
+    /// `size` pairs, with sections from 1 to 99.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let mut assignment = || {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        Some(
+            (0..size)
+                .map(|_| {
+                    let ((a, b), (c, d)) = (assignment(), assignment());
+                    format!("{a}-{b},{c}-{d}\n")
+                })
+                .collect(),
+        )
+    }
+}
+
+const SAMPLE01: &str = r#"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#;
+
+#[cfg(test)]
+const SAMPLE02: &str = r#"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day04.run1(SAMPLE01.trim()), 2);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day04.run2(SAMPLE02.trim()), 4);
+    }
+}
@@ -0,0 +1,289 @@
+use std::{collections::VecDeque, ops::Deref};
+
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+
+#[derive(Debug)]
+struct Crate(Option<char>);
+
+impl Crate {
+    fn parse(ctx: &ParseContext, line: &str) -> Result<Vec<Self>, ParseError> {
+        line.as_bytes()
+            .chunks(4)
+            .enumerate()
+            .map(|(i, chunk)| match chunk {
+                [b' ', b' ', b' ', ..] => Ok(Crate(None)),
+                [b'[', c, b']', ..] if c.is_ascii_alphabetic() => Ok(Crate(Some(*c as char))),
+                _ => Err(ctx.error(
+                    line.get(i * 4..).unwrap_or(line),
+                    "a crate like `[A]`, or three spaces",
+                )),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Move {
+    qty: usize,
+    src: usize,
+    dest: usize,
+}
+
+impl Move {
+    /// Parses a move between the stacks with `heights`, which can't take
+    /// more crates than its source stack holds.
+    fn parse(ctx: &ParseContext, line: &str, heights: &[usize]) -> Result<Self, ParseError> {
+        let stacks = heights.len();
+        let stack = |s: &str| match ctx.number(s)? {
+            n @ 1.. if n <= stacks => Ok(n),
+            _ => Err(ctx.error(s, format!("a stack from 1 to {stacks}"))),
+        };
+
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["move", qty, "from", src, "to", dest] => {
+                let m = Self {
+                    qty: ctx.number(qty)?,
+                    src: stack(src)?,
+                    dest: stack(dest)?,
+                };
+                let height = heights[m.src - 1];
+                if m.qty > height {
+                    return Err(ctx.error(
+                        qty,
+                        format!("at most {height} crates, as many as stack {src} holds"),
+                    ));
+                }
+                Ok(m)
+            }
+            _ => Err(ctx.error(line, "a move like `move 1 from 2 to 1`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Problem(Vec<VecDeque<char>>, Vec<Move>);
+
+impl Problem {
+    fn parse(ctx: &ParseContext, lines: &str) -> Result<Self, ParseError> {
+        let mut lines = lines.trim_start_matches('\n').lines();
+
+        let mut crates = Vec::new();
+        let mut labels = "";
+        for line in lines.by_ref() {
+            if line.starts_with(" 1") {
+                labels = line;
+                break;
+            }
+            for (col, crate_item) in Crate::parse(ctx, line)?.into_iter().enumerate() {
+                if crates.len() <= col {
+                    crates.push(VecDeque::new());
+                }
+                if let Crate(Some(c)) = crate_item {
+                    crates[col].push_front(c);
+                }
+            }
+        }
+
+        lines.next();
+
+        // Every stack needs a crate on top at the end, so remember which
+        // move last emptied each one to point at it if none comes back.
+        let mut heights = crates.iter().map(VecDeque::len).collect::<Vec<_>>();
+        let mut emptied_by = vec![None; crates.len()];
+        let mut moves = Vec::new();
+        for line in lines.filter(|l| !l.is_empty()) {
+            let m = Move::parse(ctx, line, &heights)?;
+            heights[m.src - 1] -= m.qty;
+            heights[m.dest - 1] += m.qty;
+            if heights[m.src - 1] == 0 {
+                emptied_by[m.src - 1] = Some(line);
+            }
+            moves.push(m);
+        }
+
+        if let Some(i) = heights.iter().position(|&height| height == 0) {
+            return Err(match emptied_by[i] {
+                Some(line) => ctx.error(line, format!("a move leaving a crate on stack {}", i + 1)),
+                None => ctx.error(
+                    labels.split_whitespace().nth(i).unwrap_or(labels),
+                    "a stack with a crate on it",
+                ),
+            });
+        }
+
+        Ok(Self(crates, moves))
+    }
+
+    fn tops(&self) -> String {
+        self.0.iter().map(|i| i.back().unwrap()).collect()
+    }
+
+    fn apply9000(&mut self) {
+        let stacks = &mut self.0;
+        for Move { qty, src, dest } in &self.1 {
+            for _ in 0..*qty {
+                let c = stacks[src - 1].pop_back().unwrap();
+                stacks[dest - 1].push_back(c);
+            }
+        }
+    }
+
+    fn apply9001(&mut self) {
+        let stacks = &mut self.0;
+        for Move { qty, src, dest } in &self.1 {
+            for c in (0..*qty)
+                .map(|_| stacks[src - 1].pop_back().unwrap())
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+            {
+                stacks[dest - 1].push_back(c);
+            }
+        }
+    }
+}
+
+impl Deref for Crate {
+    type Target = Option<char>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Problem;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Problem::parse(&ParseContext::new(Self::DAY, input), input)
+    }
+
+    fn part1(&self, p: &Self::Input<'_>) -> String {
+        let mut p = p.clone();
+        p.apply9000();
+        p.tops()
+    }
+
+    fn part2(&self, p: &Self::Input<'_>) -> String {
+        let mut p = p.clone();
+        p.apply9001();
+        p.tops()
+    }
+
+    /// Nine stacks of crates and `size` moves, each leaving at least one
+    /// crate behind so that every stack still has a top at the end.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let mut heights = (0..9).map(|_| rng.range(2..=8)).collect::<Vec<usize>>();
+        let tallest = *heights.iter().max().unwrap();
+
+        let mut input = String::new();
+        for level in (0..tallest).rev() {
+            let row = heights
+                .iter()
+                .map(|&height| {
+                    if level < height {
+                        format!("[{}]", rng.range(b'A'..=b'Z') as char)
+                    } else {
+                        "   ".to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+        let labels = (1..=heights.len())
+            .map(|i| format!(" {i} "))
+            .collect::<Vec<_>>();
+        input += &labels.join(" ");
+        input.push_str("\n\n");
+
+        for _ in 0..size {
+            let sources = (0..heights.len())
+                .filter(|&i| heights[i] > 1)
+                .collect::<Vec<_>>();
+            let src = *rng.pick(&sources);
+            let dest = (src + rng.range(1..heights.len())) % heights.len();
+            let qty = rng.range(1..heights[src]);
+            heights[src] -= qty;
+            heights[dest] += qty;
+            input += &format!("move {qty} from {} to {}\n", src + 1, dest + 1);
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day05.run1(SAMPLE01), "CMZ");
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day05.run2(SAMPLE01), "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day05
+            .parse(&SAMPLE01.replace("move 3 from 1 to 3", "move 3 from 1 to 4"))
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (7, 18));
+        assert_eq!(e.found, "4");
+
+        let e = Day05.parse(&SAMPLE01.replace("[Z]", "(Z)")).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let e = Day05
+            .parse(&SAMPLE01.replace("move 3 from 1 to 3", "move 4 from 1 to 3"))
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (7, 6));
+        assert_eq!(e.expected, "at most 3 crates, as many as stack 1 holds");
+
+        let e = Day05
+            .parse(&SAMPLE01.replace("move 1 from 1 to 2\n", ""))
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (8, 1));
+        assert_eq!(e.found, "move 2 from 2 to 1");
+
+        let e = Day05
+            .parse(
+                &SAMPLE01
+                    .replace("[Z] [M] [P]", "[Z] [M]    ")
+                    .replace("move 1 from 2 to 1", "move 1 from 3 to 1"),
+            )
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (6, 6));
+        assert_eq!(e.expected, "at most 0 crates, as many as stack 3 holds");
+
+        let e = Day05
+            .parse("[A]    \n 1   2 \n\nmove 1 from 1 to 1\n")
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+        assert_eq!(e.found, "2");
+    }
+}
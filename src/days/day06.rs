@@ -0,0 +1,98 @@
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+use itertools::Itertools;
+use log::trace;
+
+/// Number of characters read once the last `len` of them are all
+/// different, if that ever happens.
+fn marker(stream: &str, len: usize) -> Option<usize> {
+    stream
+        .chars()
+        .collect::<Vec<_>>()
+        .windows(len)
+        .position(|w| w.iter().all_unique())
+        .map(|i| i + len)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = &'a str;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let stream = input.trim();
+        // A start-of-message marker is a start-of-packet marker too.
+        match marker(stream, 14) {
+            Some(_) => Ok(stream),
+            None => Err(ParseContext::new(Self::DAY, input)
+                .error(stream, "a datastream with 14 different characters in a row")),
+        }
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> usize {
+        let end = marker(input, 4).unwrap_or_default();
+        trace!("start-of-packet marker ends at {end}");
+        end
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> usize {
+        marker(input, 14).unwrap_or_default()
+    }
+
+    /// A datastream `size` characters long. The first third only uses three
+    /// letters, so it can't hold a start-of-packet marker, and the second
+    /// only thirteen, so it can't hold a start-of-message marker, which
+    /// comes right after it.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(42);
+        let mut letters = ('a'..='z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+
+        let mut stream = String::with_capacity(size);
+        stream.extend((0..size / 3).map(|_| *rng.pick(&letters[..3])));
+        stream.extend((size / 3..size * 2 / 3).map(|_| *rng.pick(&letters[..13])));
+        rng.shuffle(&mut letters);
+        stream.extend(&letters[..14]);
+        while stream.len() < size {
+            stream.push(*rng.pick(&letters));
+        }
+        Some(stream)
+    }
+}
+
+const SAMPLE01: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day06.run1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
+        assert_eq!(Day06.run1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(Day06.run1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(Day06.run1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(Day06.run1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day06.run2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
+        assert_eq!(Day06.run2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(Day06.run2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(Day06.run2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(Day06.run2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day06.parse("mjqjpqmgbljsph\n").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (6, 1, 1));
+        assert_eq!(e.found, "mjqjpqmgbljsph");
+        assert!(Day06.parse("").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::HashSet;
+
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum Line<'a> {
+    CdEnter(&'a str),
+    CdExit,
+    Ls,
+    File(usize, &'a str),
+    Directory(&'a str),
+}
+
+impl<'a> Line<'a> {
+    fn parse(ctx: &ParseContext, l: &'a str) -> Result<Self, ParseError> {
+        Ok(if l.starts_with("$ cd ..") {
+            Line::CdExit
+        } else if let Some(ll) = l.strip_prefix("$ cd ") {
+            Line::CdEnter(ll)
+        } else if l.starts_with("$ ls") {
+            Line::Ls
+        } else if let Some(ll) = l.strip_prefix("dir ") {
+            Line::Directory(ll)
+        } else if let Some((size, name)) = l.split_once(' ') {
+            Line::File(ctx.number(size)?, name)
+        } else {
+            return Err(ctx.error(l, "a command, a directory or a file"));
+        })
+    }
+}
+
+fn visit<'a, F>(it: &mut impl Iterator<Item = Line<'a>>, visitor: &mut F) -> usize
+where
+    F: FnMut(usize),
+{
+    let mut dsize = 0usize;
+
+    loop {
+        match it.next() {
+            Some(Line::CdEnter(_)) => dsize += visit(it, visitor),
+            Some(Line::CdExit) | None => break,
+            Some(Line::Ls) => (),
+            Some(Line::File(size, _)) => dsize += size,
+            Some(Line::Directory(_)) => (),
+        }
+    }
+
+    visitor(dsize);
+    dsize
+}
+
+/// Appends the output of listing a directory and then exploring each of its
+/// subdirectories, taking up about `budget` lines. The files are written
+/// without a size, and their lines added to `files` to fill it in later.
+fn explore(rng: &mut Rng, lines: &mut Vec<String>, files: &mut Vec<usize>, budget: usize) {
+    let file_count = rng.range(0..=5);
+    let remaining = budget.saturating_sub(1 + file_count);
+    // Each subdirectory takes at least a `dir` line, a `cd` in and out, and
+    // an `ls`.
+    let dir_count = rng.range(1..=4).min(remaining / 4);
+
+    let mut kinds = [vec![false; file_count], vec![true; dir_count]].concat();
+    rng.shuffle(&mut kinds);
+    let mut names = HashSet::new();
+    let mut dirs = Vec::new();
+    lines.push("$ ls".to_string());
+    for is_dir in kinds {
+        let name = loop {
+            let mut name = (0..rng.range(1..=8))
+                .map(|_| rng.range(b'a'..=b'z') as char)
+                .collect::<String>();
+            if !is_dir && rng.one_in(2) {
+                name.push('.');
+                name.extend((0..3).map(|_| rng.range(b'a'..=b'z') as char));
+            }
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+        if is_dir {
+            lines.push(format!("dir {name}"));
+            dirs.push(name);
+        } else {
+            files.push(lines.len());
+            lines.push(name);
+        }
+    }
+
+    for dir in dirs {
+        lines.push(format!("$ cd {dir}"));
+        explore(rng, lines, files, (remaining - 3 * dir_count) / dir_count);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<Line<'a>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        input
+            .trim()
+            .lines()
+            .skip(1)
+            .map(|l| Line::parse(&ctx, l))
+            .collect()
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> usize {
+        let mut cumulative_size = 0usize;
+        visit(&mut lines.iter().copied(), &mut |u| {
+            if u < 100000 {
+                cumulative_size += u;
+            }
+        });
+        cumulative_size
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> usize {
+        const TOTAL_SPACE: usize = 70000000;
+        const NEEDED_SPACE: usize = 30000000;
+
+        let used_space = visit(&mut lines.iter().copied(), &mut |_| {});
+
+        let avail_space = TOTAL_SPACE - used_space;
+        let mut deleted_size = usize::MAX;
+
+        visit(&mut lines.iter().copied(), &mut |u| {
+            if u < deleted_size && (avail_space + u) > NEEDED_SPACE {
+                deleted_size = u
+            }
+        });
+
+        deleted_size
+    }
+
+    /// About `size` lines of terminal output. The files add up to between 40
+    /// and 65 million, so that some space has to be freed up.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let mut lines = vec!["$ cd /".to_string()];
+        let mut files = Vec::new();
+        explore(rng, &mut lines, &mut files, size.saturating_sub(1));
+
+        let weights = files
+            .iter()
+            .map(|_| rng.range(1..=9usize) << rng.range(0..17))
+            .collect::<Vec<_>>();
+        let total = weights.iter().sum::<usize>().max(1);
+        let used = rng
+            .range(40_000_000..=65_000_000usize)
+            .min(69_999_999usize.saturating_sub(files.len()));
+        for (line, weight) in files.into_iter().zip(weights) {
+            lines[line] = format!("{} {}", (weight * used / total).max(1), lines[line]);
+        }
+
+        lines.push(String::new());
+        Some(lines.join("\n"))
+    }
+}
+
+const SAMPLE01: &str = r#"
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day07.run1(SAMPLE01), 95437);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day07.run2(SAMPLE01), 24933642);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    geom::Dir4,
+    grid::{Grid, Pos},
+    rng::Rng,
+    ParseContext, ParseError, Solution,
+};
+use log::trace;
+
+/// Number of trees seen from the one at `pos` looking towards `dir`, up to
+/// the edge or the first tree at least as tall.
+fn viewing_distance(trees: &Grid<u8>, pos: Pos, dir: Dir4) -> usize {
+    let height = trees[pos];
+    let mut count = 0;
+    for tree in trees.ray(pos, dir) {
+        count += 1;
+        if trees[tree] >= height {
+            break;
+        }
+    }
+    count
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        Grid::parse(&ctx, input.trim(), "a tree height from 0 to 9", |c| {
+            c.to_digit(10).map(|h| h as u8)
+        })
+    }
+
+    fn part1(&self, trees: &Self::Input<'_>) -> usize {
+        trees
+            .iter()
+            .filter(|&(pos, &height)| {
+                Dir4::ALL
+                    .into_iter()
+                    .any(|dir| trees.ray(pos, dir).all(|tree| trees[tree] < height))
+            })
+            .count()
+    }
+
+    fn part2(&self, trees: &Self::Input<'_>) -> usize {
+        trees
+            .positions()
+            .map(|pos| {
+                let [up, right, down, left] =
+                    Dir4::ALL.map(|dir| viewing_distance(trees, pos, dir));
+
+                let scenic_score = left * right * up * down;
+                trace!("{pos}: {scenic_score} = {left} * {right} * {up} * {down}");
+
+                scenic_score
+            })
+            .max()
+            .unwrap()
+    }
+
+    /// A square of trees `size` on each side.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let side = size.max(1);
+        let mut input = String::with_capacity(side * (side + 1));
+        for _ in 0..side {
+            input.extend((0..side).map(|_| rng.range(b'0'..=b'9') as char));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"
+30373
+25512
+65332
+33549
+35390
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day08.run1(SAMPLE01), 21);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day08.run2(SAMPLE01), 8);
+    }
+}
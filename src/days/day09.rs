@@ -0,0 +1,223 @@
+use std::collections::HashSet;
+
+use crate::{
+    geom::{Dir4, Point2},
+    rng::Rng,
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Solution,
+};
+
+#[derive(Clone, Copy)]
+pub struct Move {
+    dir: Dir4,
+    steps: usize,
+}
+
+impl Move {
+    fn parse(ctx: &ParseContext, line: &str) -> Result<Self, ParseError> {
+        let (dir, steps) = line
+            .split_once(' ')
+            .ok_or_else(|| ctx.error(line, "a move like `R 4`"))?;
+        let dir = match dir {
+            "R" => Dir4::Right,
+            "L" => Dir4::Left,
+            "U" => Dir4::Up,
+            "D" => Dir4::Down,
+            _ => return Err(ctx.error(dir, "one of `R`, `L`, `U` or `D`")),
+        };
+        Ok(Move {
+            dir,
+            steps: ctx.number(steps)?,
+        })
+    }
+}
+
+/// Moves `tail` a step towards `head`, unless they're touching.
+fn follow(tail: &mut Point2<isize>, head: Point2<isize>) {
+    if tail.chebyshev(head) > 1 {
+        *tail += (head - *tail).signum();
+    }
+}
+
+/// Positions visited by the last knot of a rope `knots` long.
+fn simulate(moves: &[Move], knots: usize) -> usize {
+    let mut rope = vec![Point2::default(); knots];
+    let mut visited = HashSet::from([Point2::default()]);
+
+    for m in moves {
+        for _ in 0..m.steps {
+            rope[0] += m.dir.into();
+            for i in 1..knots {
+                let head = rope[i - 1];
+                follow(&mut rope[i], head);
+            }
+            visited.insert(rope[knots - 1]);
+            viz::step(|| frame(&rope, &visited));
+        }
+    }
+    viz::show(|| frame(&rope, &visited));
+
+    visited.len()
+}
+
+/// The rope over the positions its tail visited, head as `H` and the other
+/// knots by their number.
+fn frame(rope: &[Point2<isize>], visited: &HashSet<Point2<isize>>) -> Frame {
+    let origin = Point2::<isize>::default();
+    let (min, max) = rope
+        .iter()
+        .chain(visited)
+        .fold((origin, origin), |(min, max), p| {
+            (
+                Point2::new(min.x.min(p.x), min.y.min(p.y)),
+                Point2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+    let size = max - min + Point2::new(1, 1);
+
+    Frame::grid(size.x as usize, size.y as usize, |p| {
+        let p = min + Point2::new(p.x as isize, p.y as isize);
+        match rope.iter().position(|&knot| knot == p) {
+            Some(0) => ('H', Style::Lit),
+            Some(i) => (char::from_digit(i as u32 % 10, 10).unwrap(), Style::Lit),
+            None if p == origin => ('s', Style::Plain),
+            None if visited.contains(&p) => ('#', Style::Filled),
+            None => ('.', Style::Empty),
+        }
+    })
+    .animated()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<Move>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        input
+            .trim()
+            .lines()
+            .map(|line| Move::parse(&ctx, line))
+            .collect()
+    }
+
+    fn part1(&self, moves: &Self::Input<'_>) -> usize {
+        simulate(moves, 2)
+    }
+
+    fn part2(&self, moves: &Self::Input<'_>) -> usize {
+        simulate(moves, 10)
+    }
+
+    /// `size` moves of up to 20 steps.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        Some(
+            (0..size)
+                .map(|_| {
+                    format!(
+                        "{} {}\n",
+                        rng.pick(&['R', 'L', 'U', 'D']),
+                        rng.range(1..=20)
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+const SAMPLE01: &str = r#"
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"#;
+
+#[cfg(test)]
+const SAMPLE02: &str = r#"
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"#;
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day09.run1(SAMPLE01), 13);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day09.run2(SAMPLE01), 1);
+        assert_eq!(Day09.run2(SAMPLE02), 36);
+    }
+
+    /// Tail positions visited, moving each knot to whichever square around
+    /// it is nearest to the knot ahead whenever they stop touching.
+    fn reference(moves: &[(char, usize)], knots: usize) -> usize {
+        let mut rope = vec![(0i32, 0i32); knots];
+        let mut visited = HashSet::from([(0, 0)]);
+
+        for &(dir, steps) in moves {
+            let (dx, dy) = match dir {
+                'R' => (1, 0),
+                'L' => (-1, 0),
+                'U' => (0, -1),
+                _ => (0, 1),
+            };
+            for _ in 0..steps {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for i in 1..knots {
+                    let ((hx, hy), (tx, ty)) = (rope[i - 1], rope[i]);
+                    if (hx - tx).abs() > 1 || (hy - ty).abs() > 1 {
+                        rope[i] = (-1..=1)
+                            .flat_map(|dx| (-1..=1).map(move |dy| (tx + dx, ty + dy)))
+                            .min_by_key(|&(x, y)| (hx - x).pow(2) + (hy - y).pow(2))
+                            .unwrap();
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+
+        visited.len()
+    }
+
+    fn moves() -> impl Strategy<Value = Vec<(char, usize)>> {
+        vec(
+            (prop::sample::select(vec!['R', 'L', 'U', 'D']), 1..8usize),
+            1..40,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_reference(moves in moves()) {
+            let input = moves
+                .iter()
+                .map(|(dir, steps)| format!("{dir} {steps}\n"))
+                .collect::<String>();
+            prop_assert_eq!(Day09.run1(&input), reference(&moves, 2));
+            prop_assert_eq!(Day09.run2(&input), reference(&moves, 10));
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    rng::Rng,
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Screen, Solution,
+};
+use log::trace;
+
+struct Signal(isize);
+
+impl Signal {
+    fn parse(ctx: &ParseContext, input: &str) -> Result<Vec<Signal>, ParseError> {
+        let mut signals = Vec::new();
+
+        for l in input.trim().lines() {
+            if let Some(s) = l.strip_prefix("addx ") {
+                signals.extend([Signal(0), Signal(ctx.number(s)?)]);
+            } else if l == "noop" {
+                signals.push(Signal(0));
+            } else {
+                return Err(ctx.error(l, "`noop`, or `addx` and a number"));
+            }
+        }
+
+        signals.extend([Signal(0), Signal(0)]);
+        Ok(signals)
+    }
+}
+
+fn cycles(signals: &[isize]) -> impl Iterator<Item = isize> + '_ {
+    signals.iter().scan(1isize, |signal, add| {
+        let cur_signal = *signal;
+        *signal += add;
+        Some(cur_signal)
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<isize>;
+    type Output1 = usize;
+    type Output2 = Screen;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let signals = Signal::parse(&ParseContext::new(Self::DAY, input), input)?;
+        Ok(signals.into_iter().map(|i| i.0).collect())
+    }
+
+    fn part1(&self, signals: &Self::Input<'_>) -> usize {
+        cycles(signals)
+            .enumerate()
+            .map(|(idx, signal)| (idx as isize + 1, signal))
+            .filter(|&(idx, _)| idx == 20 || ((idx + 20) % 40 == 0))
+            .fold(0isize, |o, (idx, signal)| {
+                trace!("cycle {idx}: {signal} * {idx} = {}", idx * signal);
+                o + idx * signal
+            }) as usize
+    }
+
+    fn part2(&self, signals: &Self::Input<'_>) -> Screen {
+        let crt = cycles(signals).enumerate().take(40 * 6).fold(
+            vec![false; 40 * 6],
+            |mut crt, (idx, signal)| {
+                let col = idx % 40;
+                if (col as isize - signal).abs() <= 1 {
+                    crt[idx] = true;
+                }
+
+                crt
+            },
+        );
+
+        viz::show(|| {
+            Frame::grid(40, 6, |p| {
+                if crt[p.x + p.y * 40] {
+                    ('#', Style::Lit)
+                } else {
+                    ('.', Style::Dim)
+                }
+            })
+            .title("CRT")
+        });
+
+        Screen::new(40, crt)
+    }
+
+    /// `size` instructions, and at least enough to draw the whole screen. The
+    /// sprite stays within it.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let mut x = 1;
+        let mut input = String::new();
+        for _ in 0..size.max(240) {
+            if rng.one_in(3) {
+                input += "noop\n";
+            } else {
+                let mut v = rng.range(-10..=10);
+                if !(0..40).contains(&(x + v)) {
+                    v = -v;
+                }
+                x += v;
+                input += &format!("addx {v}\n");
+            }
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day10.run1(SAMPLE01), 13140);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(
+            Day10.run2(SAMPLE01).to_string(),
+            "##..##..##..##..##..##..##..##..##..##..\n\
+             ###...###...###...###...###...###...###.\n\
+             ####....####....####....####....####....\n\
+             #####.....#####.....#####.....#####.....\n\
+             ######......######......######......####\n\
+             #######.......#######.......#######....."
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use std::collections::VecDeque;
+
+use crate::{rng::Rng, select, ParseContext, ParseError, Solution};
+use itertools::Itertools;
+use log::{log_enabled, trace, Level};
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add(i64),
+    Sub(i64),
+    Mul(i64),
+    Div(i64),
+    Square,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<i64>,
+    op: Op,
+    test: i64,
+    if_true: usize,
+    if_false: usize,
+}
+
+struct Toss(usize, i64);
+
+impl Monkey {
+    fn parse(ctx: &ParseContext, input: &str) -> Result<Vec<Self>, ParseError> {
+        let count = input.split("\n\n").count();
+        let monkeys = input
+            .split("\n\n")
+            .map(|m| Self::parse_one(ctx, m, count))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Monkey business takes the two most active monkeys.
+        if monkeys.len() < 2 {
+            return Err(ctx.error(&input[input.len()..], "a second monkey"));
+        }
+        Ok(monkeys)
+    }
+
+    fn parse_one(ctx: &ParseContext, block: &str, count: usize) -> Result<Self, ParseError> {
+        let mut lines = block.lines();
+        let mut field = |prefix: &str| match lines.next() {
+            Some(line) => line
+                .strip_prefix(prefix)
+                .ok_or_else(|| ctx.error(line, format!("`{}`", prefix.trim()))),
+            None => Err(ctx.error(&block[block.len()..], format!("`{}`", prefix.trim()))),
+        };
+        let target = |s: &str| match ctx.number(s)? {
+            n if n < count => Ok(n),
+            _ => Err(ctx.error(s, format!("a monkey below {count}"))),
+        };
+
+        field("Monkey ")?;
+
+        let items = field("  Starting items: ")?
+            .split(", ")
+            .map(|i| ctx.number(i))
+            .collect::<Result<_, _>>()?;
+
+        let op = field("  Operation: new = old ")?;
+        let op = match op.split_once(' ') {
+            Some(("*", "old")) => Op::Square,
+            Some((o, n)) => {
+                let val = ctx.number(n)?;
+                match o {
+                    "+" => Op::Add(val),
+                    "-" => Op::Sub(val),
+                    "*" => Op::Mul(val),
+                    "/" if val == 0 => return Err(ctx.error(n, "a divisor other than 0")),
+                    "/" => Op::Div(val),
+                    _ => return Err(ctx.error(o, "one of `+`, `-`, `*` or `/`")),
+                }
+            }
+            None => return Err(ctx.error(op, "an operator and a number")),
+        };
+
+        let test = field("  Test: divisible by ")?;
+        let test = match ctx.number(test)? {
+            n @ 1.. => n,
+            _ => return Err(ctx.error(test, "a positive number")),
+        };
+
+        let if_true = target(field("    If true: throw to monkey ")?)?;
+        let if_false = target(field("    If false: throw to monkey ")?)?;
+
+        Ok(Monkey {
+            items,
+            op,
+            test,
+            if_true,
+            if_false,
+        })
+    }
+
+    fn iter_mut1(&'_ mut self) -> MonkeyRound<'_> {
+        MonkeyRound(self, 3, i64::MAX)
+    }
+
+    fn iter_mut2(&'_ mut self, modulo: i64) -> MonkeyRound<'_> {
+        MonkeyRound(self, 1, modulo)
+    }
+
+    fn receive(&mut self, i: i64) {
+        self.items.push_back(i);
+    }
+}
+
+struct MonkeyRound<'a>(&'a mut Monkey, i64, i64);
+
+impl<'a> Iterator for MonkeyRound<'a> {
+    type Item = Toss;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.0.items.pop_front()?;
+        let item = match self.0.op {
+            Op::Add(i) => item + i,
+            Op::Sub(i) => item - i,
+            Op::Mul(i) => item * i,
+            Op::Div(i) => item / i,
+            Op::Square => item * item,
+        } / self.1;
+        let item = item % self.2;
+
+        if item % self.0.test == 0 {
+            Some(Toss(self.0.if_true, item))
+        } else {
+            Some(Toss(self.0.if_false, item))
+        }
+    }
+}
+
+/// Logs the items each monkey holds after `round`.
+fn log_round(round: usize, monkeys: &[Monkey]) {
+    if !log_enabled!(Level::Trace) {
+        return;
+    }
+
+    trace!("round {round}");
+    for (i, m) in monkeys.iter().enumerate() {
+        trace!("  monkey {i}: {}", m.items.iter().join(", "));
+    }
+}
+
+/// Product of the two largest numbers of items inspected.
+fn monkey_business(monkey_actions: Vec<usize>) -> usize {
+    select::top_k(monkey_actions, 2)
+        .into_iter()
+        .map(|(_, n)| n)
+        .product()
+}
+
+/// Whether an item starting at `monkey` gets through the 20 rounds of part 1
+/// without its worry level overflowing. Items move independently of each
+/// other, so it's enough to follow this one.
+fn survives_part1(monkeys: &[Monkey], mut monkey: usize, mut worry: i64) -> bool {
+    let mut round = 0;
+    while round < 20 {
+        let m = &monkeys[monkey];
+        let new = match m.op {
+            Op::Add(i) => worry.checked_add(i),
+            Op::Sub(i) => worry.checked_sub(i),
+            Op::Mul(i) => worry.checked_mul(i),
+            Op::Div(i) => worry.checked_div(i),
+            Op::Square => worry.checked_mul(worry),
+        };
+        let Some(new) = new else {
+            return false;
+        };
+        worry = new / 3;
+        let target = if worry % m.test == 0 {
+            m.if_true
+        } else {
+            m.if_false
+        };
+        // Monkeys later in the round get to the item before it ends.
+        if target < monkey {
+            round += 1;
+        }
+        monkey = target;
+    }
+    true
+}
+
+/// Eight monkeys with distinct prime tests, one of them squaring.
+fn random_monkeys(rng: &mut Rng) -> Vec<Monkey> {
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let square = rng.range(0..8);
+    (0..8)
+        .map(|i| {
+            let op = if i == square {
+                Op::Square
+            } else if rng.one_in(3) {
+                Op::Mul(rng.range(2..=19))
+            } else {
+                Op::Add(rng.range(1..=8))
+            };
+            let if_true = (i + rng.range(1..8)) % 8;
+            let if_false = loop {
+                let target = (i + rng.range(1..8)) % 8;
+                if target != if_true {
+                    break target;
+                }
+            };
+            Monkey {
+                items: VecDeque::new(),
+                op,
+                test: primes[i],
+                if_true,
+                if_false,
+            }
+        })
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<Monkey>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Monkey::parse(&ParseContext::new(Self::DAY, input), input.trim())
+    }
+
+    fn part1(&self, monkeys: &Self::Input<'_>) -> usize {
+        let mut monkeys = monkeys.clone();
+        let mut monkey_actions = vec![0usize; monkeys.len()];
+
+        for i in 1..=20 {
+            for m in 0..monkeys.len() {
+                let actions = monkeys[m].iter_mut1().collect::<Vec<_>>();
+                monkey_actions[m] += actions.len();
+                for Toss(tgt_monkey, item) in actions {
+                    monkeys[tgt_monkey].receive(item);
+                }
+            }
+            log_round(i, &monkeys);
+        }
+
+        monkey_business(monkey_actions)
+    }
+
+    fn part2(&self, monkeys: &Self::Input<'_>) -> usize {
+        let mut monkeys = monkeys.clone();
+        let mut monkey_actions = vec![0usize; monkeys.len()];
+
+        let modulo = monkeys.iter().map(|i| i.test).product();
+
+        for i in 1..=10000 {
+            for m in 0..monkeys.len() {
+                let actions = monkeys[m].iter_mut2(modulo).collect::<Vec<_>>();
+                monkey_actions[m] += actions.len();
+                for Toss(tgt_monkey, item) in actions {
+                    monkeys[tgt_monkey].receive(item);
+                }
+            }
+            log_round(i, &monkeys);
+        }
+
+        monkey_business(monkey_actions)
+    }
+
+    /// Eight monkeys holding `size` items between them, all of which part 1
+    /// can follow without overflowing.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let (mut monkeys, safe) = loop {
+            let monkeys = random_monkeys(rng);
+            let safe = (0..monkeys.len())
+                .map(|m| {
+                    (50..=99)
+                        .filter(|&worry| survives_part1(&monkeys, m, worry))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            if safe.iter().all(|worries| !worries.is_empty()) {
+                break (monkeys, safe);
+            }
+        };
+        for i in 0..size.max(monkeys.len()) {
+            let m = if i < monkeys.len() {
+                i
+            } else {
+                rng.range(0..monkeys.len())
+            };
+            monkeys[m].receive(*rng.pick(&safe[m]));
+        }
+
+        let blocks = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let op = match m.op {
+                    Op::Add(v) => format!("+ {v}"),
+                    Op::Sub(v) => format!("- {v}"),
+                    Op::Mul(v) => format!("* {v}"),
+                    Op::Div(v) => format!("/ {v}"),
+                    Op::Square => "* old".to_string(),
+                };
+                format!(
+                    "Monkey {i}:\n  Starting items: {}\n  Operation: new = old {op}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}\n",
+                    m.items.iter().join(", "),
+                    m.test,
+                    m.if_true,
+                    m.if_false
+                )
+            })
+            .collect::<Vec<_>>();
+        Some(blocks.join("\n"))
+    }
+}
+
+const SAMPLE01: &str = r#"
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+"#;
+
+#[test]
+fn test1() {
+    assert_eq!(Day11.run1(SAMPLE01), 10605);
+}
+
+#[test]
+fn test2() {
+    assert_eq!(Day11.run2(SAMPLE01), 2713310158);
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Day11
+        .parse(&SAMPLE01.replace("old + 6", "old / 0"))
+        .unwrap_err();
+    assert_eq!((e.day, e.line, e.column), (11, 11, 26));
+    assert_eq!(e.found, "0");
+
+    let one = SAMPLE01.trim().split("\n\n").next().unwrap();
+    let one = one
+        .replace("monkey 2", "monkey 0")
+        .replace("monkey 3", "monkey 0");
+    let e = Day11.parse(&one).unwrap_err();
+    assert_eq!(e.expected, "a second monkey");
+}
@@ -0,0 +1,195 @@
+use crate::{
+    grid::{Grid, Pos},
+    rng::Rng,
+    search::{bfs, Search},
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Spot(u8),
+    Start,
+    End,
+}
+
+pub struct Heightmap {
+    grid: Grid<Cell>,
+    s: Pos,
+    e: Pos,
+}
+
+impl Heightmap {
+    fn neighborhood(&self, src: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.grid
+            .neighbours4(src)
+            .filter(move |&dst| match (self.grid[dst], self.grid[src]) {
+                (Cell::Start, _) => true,
+                (Cell::End, _) => false,
+                (Cell::Spot(s), Cell::End) => s >= 24,
+                (Cell::Spot(a), Cell::Spot(b)) => b <= a + 1,
+                _ => false,
+            })
+    }
+
+    /// Distances to the end, walking backwards from it.
+    fn search(&self) -> Search<Pos> {
+        bfs([self.e], |pos| self.neighborhood(pos))
+    }
+
+    /// Animates `search` spreading out from the end one distance at a time,
+    /// then shows the path it found to `from`.
+    fn show(&self, search: &Search<Pos>, from: Pos) {
+        if !viz::enabled() {
+            return;
+        }
+
+        let farthest = search.iter().map(|(_, dist)| dist).max().unwrap_or(0);
+        for frontier in 0..=farthest {
+            viz::step(|| {
+                self.frame(|pos| match search.distance(pos) {
+                    Some(dist) if dist == frontier => Style::Lit,
+                    Some(dist) if dist < frontier => Style::Filled,
+                    _ => Style::Empty,
+                })
+            });
+        }
+
+        let path = search.path(from).unwrap_or_default();
+        viz::show(|| {
+            self.frame(|pos| match search.distance(pos) {
+                _ if path.contains(&pos) => Style::Good,
+                Some(_) => Style::Filled,
+                None => Style::Empty,
+            })
+        });
+    }
+
+    /// The heights as letters, styled by `style`.
+    fn frame(&self, mut style: impl FnMut(Pos) -> Style) -> Frame {
+        Frame::grid(self.grid.width(), self.grid.height(), |pos| {
+            let c = match self.grid[pos] {
+                Cell::Spot(h) => (b'a' + h) as char,
+                Cell::Start => 'S',
+                Cell::End => 'E',
+            };
+            (c, style(pos))
+        })
+        .animated()
+    }
+
+    fn parse(ctx: &ParseContext, input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(
+            ctx,
+            input.trim(),
+            "a height from a to z, `S` or `E`",
+            |c| match c {
+                'S' => Some(Cell::Start),
+                'E' => Some(Cell::End),
+                'a'..='z' => Some(Cell::Spot(c as u8 - b'a')),
+                _ => None,
+            },
+        )?;
+
+        let end = &input[input.len()..];
+        Ok(Heightmap {
+            s: grid
+                .position(|&c| c == Cell::Start)
+                .ok_or_else(|| ctx.error(end, "a start square `S`"))?,
+            e: grid
+                .position(|&c| c == Cell::End)
+                .ok_or_else(|| ctx.error(end, "an end square `E`"))?,
+            grid,
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Heightmap;
+    type Output1 = Option<usize>;
+    type Output2 = Option<usize>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Heightmap::parse(&ParseContext::new(Self::DAY, input), input)
+    }
+
+    fn part1(&self, map: &Self::Input<'_>) -> Option<usize> {
+        let search = map.search();
+        map.show(&search, map.s);
+        search.distance(map.s)
+    }
+
+    fn part2(&self, map: &Self::Input<'_>) -> Option<usize> {
+        let search = map.search();
+        let (start, dist) = map
+            .grid
+            .iter()
+            .filter(|(_, c)| matches!(c, Cell::Start | Cell::Spot(0)))
+            .filter_map(|(pos, _)| Some((pos, search.distance(pos)?)))
+            .min_by_key(|&(_, dist)| dist)?;
+        map.show(&search, start);
+        Some(dist)
+    }
+
+    /// A map of about `size` squares, three times as wide as it's high and at
+    /// least 27 by 5, rising from west to east. One row climbs a step at most per square
+    /// from `S` on the west edge to `E` on the east one, and the rest are
+    /// rougher.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let width = ((size as f64 * 3.0).sqrt() as usize).max(27);
+        let height = (size / width).max(5);
+        let road = rng.range(0..height);
+        let ramp = |x: usize| (x * 25 / (width - 1)) as u8;
+
+        let mut input = String::with_capacity((width + 1) * height);
+        for y in 0..height {
+            for x in 0..width {
+                input.push(match (x, y == road) {
+                    (0, true) => 'S',
+                    (x, true) if x == width - 1 => 'E',
+                    (x, true) => (b'a' + ramp(x)) as char,
+                    (x, false) => {
+                        let h = ramp(x) as i32 + rng.range(-3..=1);
+                        (b'a' + h.clamp(0, 25) as u8) as char
+                    }
+                });
+            }
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day12.run1(SAMPLE01), Some(31));
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day12.run2(SAMPLE01), Some(29));
+    }
+
+    #[test]
+    fn test_no_path() {
+        assert_eq!(Day12.run1("SazE"), None);
+        assert_eq!(Day12.run2("SazE"), None);
+    }
+}
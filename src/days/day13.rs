@@ -0,0 +1,432 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    rng::Rng,
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Solution,
+};
+use itertools::{EitherOrBoth, Itertools};
+use log::debug;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character,
+    combinator::{all_consuming, map},
+    multi::separated_list0,
+    sequence::tuple,
+    IResult,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum AoCList {
+    Value(i64),
+    List(Vec<AoCList>),
+}
+
+impl fmt::Display for AoCList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AoCList::Value(i) => write!(f, "{i}"),
+            AoCList::List(l) => write!(f, "[{}]", l.iter().join(",")),
+        }
+    }
+}
+
+impl AoCList {
+    fn divider(i: i64) -> Self {
+        AoCList::List(vec![AoCList::List(vec![AoCList::Value(i)])])
+    }
+
+    fn singleton(&self) -> Option<&Self> {
+        match self {
+            AoCList::Value(_) => None,
+            AoCList::List(l) if l.len() == 1 => Some(&l[0]),
+            _ => None,
+        }
+    }
+
+    fn is_divider(&self) -> bool {
+        self.singleton()
+            .and_then(|i| i.singleton())
+            .map(|i| {
+                if let AoCList::Value(i) = i {
+                    *i == 2 || *i == 6
+                } else {
+                    false
+                }
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Compares two packets, writing down every step into `trace` if given one.
+fn cmp_list(
+    pkt1: &AoCList,
+    pkt2: &AoCList,
+    indent: usize,
+    mut trace: Option<&mut Frame>,
+) -> Ordering {
+    let guides = |frame: &mut Frame| {
+        for i in 0..indent {
+            frame.push("| ", if i % 2 == 0 { Style::Plain } else { Style::Dim });
+        }
+    };
+
+    if let Some(frame) = trace.as_deref_mut() {
+        for line in [pkt1.to_string(), "vs".to_string(), pkt2.to_string()] {
+            guides(frame);
+            frame.push(line, Style::Plain).end_line();
+        }
+    }
+
+    use AoCList::*;
+
+    let outcome = match (pkt1, pkt2) {
+        (Value(a), Value(b)) if a < b => Ordering::Less,
+        (Value(a), Value(b)) if a > b => Ordering::Greater,
+        (Value(_), Value(_)) => Ordering::Equal,
+        (List(pkt1), List(pkt2)) if pkt1.is_empty() && !pkt2.is_empty() => Ordering::Less,
+        (List(pkt1), List(pkt2)) if !pkt1.is_empty() && pkt2.is_empty() => Ordering::Greater,
+        (List(pkt1), List(pkt2)) => pkt1
+            .iter()
+            .zip_longest(pkt2.iter())
+            .map(|a| match a {
+                EitherOrBoth::Both(a, b) => cmp_list(a, b, indent + 1, trace.as_deref_mut()),
+                EitherOrBoth::Right(_) => Ordering::Less,
+                EitherOrBoth::Left(_) => Ordering::Greater,
+            })
+            .find(|&i| i != Ordering::Equal)
+            .unwrap_or(Ordering::Equal),
+        (List(_), Value(i2)) => cmp_list(
+            pkt1,
+            &List(vec![Value(*i2)]),
+            indent + 1,
+            trace.as_deref_mut(),
+        ),
+        (Value(i1), List(_)) => cmp_list(
+            &List(vec![Value(*i1)]),
+            pkt2,
+            indent + 1,
+            trace.as_deref_mut(),
+        ),
+    };
+
+    if let Some(frame) = trace {
+        guides(frame);
+        frame
+            .push(format!("Outcome: {outcome:?}"), Style::Plain)
+            .end_line();
+    }
+
+    outcome
+}
+
+fn parse_list(i: &str) -> IResult<&str, AoCList> {
+    alt((
+        map(character::complete::i64, AoCList::Value),
+        map(
+            tuple((tag("["), separated_list0(tag(","), parse_list), tag("]"))),
+            |(_, l, _)| AoCList::List(l),
+        ),
+    ))(i)
+}
+
+/// A random packet nested up to `depth` lists deep.
+fn random_list(rng: &mut Rng, depth: usize) -> AoCList {
+    if depth == 0 || rng.one_in(3) {
+        return AoCList::Value(rng.range(0..=10));
+    }
+    AoCList::List(
+        (0..rng.range(0..=5))
+            .map(|_| random_list(rng, depth - 1))
+            .collect(),
+    )
+}
+
+/// A random packet other than the dividers.
+fn random_packet(rng: &mut Rng) -> AoCList {
+    loop {
+        let packet = AoCList::List((0..rng.range(0..=5)).map(|_| random_list(rng, 4)).collect());
+        if !packet.is_divider() {
+            return packet;
+        }
+    }
+}
+
+fn parse_two_lists(i: &str) -> IResult<&str, (AoCList, AoCList)> {
+    let (i, (a, _, b)) = tuple((parse_list, tag("\n"), parse_list))(i)?;
+    Ok((i, (a, b)))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<(AoCList, AoCList)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let ctx = ParseContext::new(Self::DAY, input);
+        input
+            .trim()
+            .split("\n\n")
+            .map(|pair| {
+                all_consuming(parse_two_lists)(pair)
+                    .map(|(_, pair)| pair)
+                    .map_err(|e| ctx.nom_error(e, "a pair of packets like `[1,[2]]`"))
+            })
+            .collect()
+    }
+
+    fn part1(&self, lists: &Self::Input<'_>) -> usize {
+        let mut ordered = 0;
+
+        lists.iter().enumerate().for_each(|(idx, (pkt1, pkt2))| {
+            let mut trace =
+                viz::enabled().then(|| Frame::new().title(format!("=== Pair {} ===", idx + 1)));
+            let l = cmp_list(pkt1, pkt2, 0, trace.as_mut());
+            viz::show(|| {
+                let mut frame = trace.unwrap_or_default();
+                frame.push("Outcome: ", Style::Plain);
+                if l == Ordering::Less {
+                    frame.push("ordered", Style::Good);
+                } else {
+                    frame.push("unordered", Style::Bad);
+                }
+                frame
+            });
+            if l == Ordering::Less {
+                ordered += idx + 1;
+            }
+        });
+
+        debug!("final outcome {ordered}");
+
+        ordered
+    }
+
+    fn part2(&self, lists: &Self::Input<'_>) -> usize {
+        let mut lists = lists
+            .iter()
+            .flat_map(|(pkt1, pkt2)| [pkt1, pkt2])
+            .collect::<Vec<_>>();
+
+        // Only add the divider packets the input doesn't carry already.
+        let dividers = [AoCList::divider(2), AoCList::divider(6)];
+        for divider in &dividers {
+            if !lists.contains(&divider) {
+                lists.push(divider);
+            }
+        }
+
+        lists.sort_by(|pkt1, pkt2| cmp_list(pkt1, pkt2, 0, None));
+
+        viz::show(|| {
+            let mut frame = Frame::new().title("=== Sorted packets ===");
+            for (idx, l) in lists.iter().enumerate() {
+                frame.push(format!("{idx:>4}: {l}"), Style::Plain);
+                if l.is_divider() {
+                    frame.push(" divider!", Style::Good);
+                }
+                frame.end_line();
+            }
+            frame
+        });
+
+        lists
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.is_divider())
+            .map(|(idx, _)| idx + 1)
+            .product()
+    }
+
+    /// `size` pairs of packets. Half of the right packets are a copy of the
+    /// left one with its last number changed, so that comparing them has to
+    /// go deeper.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let pairs = (0..size.max(1))
+            .map(|_| {
+                let left = random_packet(rng).to_string();
+                let right = left
+                    .rfind(|c: char| c.is_ascii_digit())
+                    .filter(|_| rng.one_in(2))
+                    .map(|i| format!("{}{}{}", &left[..i], rng.range(0..=9), &left[i + 1..]))
+                    .filter(|right| !matches!(right.as_str(), "[[2]]" | "[[6]]"))
+                    .unwrap_or_else(|| random_packet(rng).to_string());
+                format!("{left}\n{right}\n")
+            })
+            .collect::<Vec<_>>();
+        Some(pairs.join("\n"))
+    }
+}
+
+const SAMPLE01: &str = r#"
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+
+"#;
+
+#[cfg(test)]
+const SAMPLE02: &str = r#"
+[[[],8,8,[]],[10,[10,[8,7],1,[5,9,9,1,7],4],5],[1,8,[5,1,[9,7,10,5],7],[[6,7],[8],[9],0,6],4]]
+[[[]],[[8,4],7,3,[]],[[],10,[5,[1],[8,3,2,1]]],[[[2,3,10,2]],4,1,4],[9,2]]
+"#;
+
+#[cfg(test)]
+const SAMPLE03: &str = r#"
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+
+[[2]]
+[[6]]
+"#;
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        Day13.run1(SAMPLE02);
+        assert_eq!(Day13.run1(SAMPLE01), 13);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day13.run2(SAMPLE03), 140);
+        // Only the divider missing from the input gets added.
+        let input = format!("{}\n\n[[2]]\n[10]", SAMPLE01.trim_end());
+        assert_eq!(Day13.run2(&input), 140);
+    }
+
+    #[derive(Debug, Clone)]
+    enum Packet {
+        Int(u8),
+        List(Vec<Packet>),
+    }
+
+    impl fmt::Display for Packet {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Packet::Int(i) => write!(f, "{i}"),
+                Packet::List(l) => write!(f, "[{}]", l.iter().join(",")),
+            }
+        }
+    }
+
+    /// The puzzle's ordering, rule by rule.
+    fn compare(left: &Packet, right: &Packet) -> Ordering {
+        let wrap = |i| Packet::List(vec![Packet::Int(i)]);
+        match (left, right) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => {
+                for (a, b) in a.iter().zip(b) {
+                    let order = compare(a, b);
+                    if order != Ordering::Equal {
+                        return order;
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (&Packet::Int(a), Packet::List(_)) => compare(&wrap(a), right),
+            (Packet::List(_), &Packet::Int(b)) => compare(left, &wrap(b)),
+        }
+    }
+
+    fn divider(i: u8) -> Packet {
+        Packet::List(vec![Packet::List(vec![Packet::Int(i)])])
+    }
+
+    /// Sum of the indices of the pairs in order.
+    fn reference1(pairs: &[(Packet, Packet)]) -> usize {
+        (1..=pairs.len())
+            .filter(|&i| compare(&pairs[i - 1].0, &pairs[i - 1].1) == Ordering::Less)
+            .sum()
+    }
+
+    /// Product of where the dividers end up, found by counting the packets
+    /// before each rather than sorting.
+    fn reference2(pairs: &[(Packet, Packet)]) -> usize {
+        let packets = pairs.iter().flat_map(|(a, b)| [a, b]).collect::<Vec<_>>();
+        let before = |divider: &Packet| {
+            packets
+                .iter()
+                .filter(|p| compare(p, divider) == Ordering::Less)
+                .count()
+        };
+        (before(&divider(2)) + 1) * (before(&divider(6)) + 2)
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let leaf = (0..=10u8).prop_map(Packet::Int);
+        let tree = leaf.prop_recursive(4, 32, 4, |inner| vec(inner, 0..4).prop_map(Packet::List));
+        vec(tree, 0..4).prop_map(Packet::List)
+    }
+
+    proptest! {
+        #[test]
+        fn test_reference(pairs in vec((packet(), packet()), 1..12)) {
+            // Packets tied with a divider could go on either side of it.
+            let dividers = [divider(2), divider(6)];
+            prop_assume!(pairs
+                .iter()
+                .flat_map(|(a, b)| [a, b])
+                .all(|p| dividers.iter().all(|d| compare(p, d) != Ordering::Equal)));
+
+            let input = pairs
+                .iter()
+                .map(|(a, b)| format!("{a}\n{b}\n\n"))
+                .collect::<String>();
+            prop_assert_eq!(Day13.run1(&input), reference1(&pairs));
+            prop_assert_eq!(Day13.run2(&input), reference2(&pairs));
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{
+    geom::Dir8,
+    grid::{Pos, SparseGrid},
+    rng::Rng,
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Solution,
+};
+use itertools::{iproduct, Itertools};
+
+/// Where the sand comes from.
+const SOURCE: Pos = Pos::new(500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Material {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cells: SparseGrid<Material>,
+    /// Area of the rocks, stretched up to the source of the sand.
+    bounds: (Pos, Pos),
+    sand: usize,
+}
+
+impl Cave {
+    fn parse(ctx: &ParseContext, input: &str) -> Result<Self, ParseError> {
+        let mut rocks = Vec::new();
+        for line in input.trim().lines() {
+            let points = line
+                .split(" -> ")
+                .map(|p| {
+                    let (x, y) = p
+                        .split_once(',')
+                        .ok_or_else(|| ctx.error(p, "a point like `498,4`"))?;
+                    Ok((p, Pos::new(ctx.number(x)?, ctx.number(y)?)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for ((_, start), (p, stop)) in points.into_iter().tuple_windows() {
+                if start.x != stop.x && start.y != stop.y {
+                    return Err(ctx.error(p, "a point in a straight line from the last one"));
+                }
+                let xr = start.x.min(stop.x)..=start.x.max(stop.x);
+                let yr = start.y.min(stop.y)..=start.y.max(stop.y);
+                rocks.extend(iproduct!(xr, yr).map(|(x, y)| Pos::new(x, y)));
+            }
+        }
+
+        let cells = rocks
+            .into_iter()
+            .map(|pos| (pos, Material::Rock))
+            .collect::<SparseGrid<_>>();
+
+        let (min, max) = cells
+            .bounds()
+            .ok_or_else(|| ctx.error(input, "a path of rock"))?;
+
+        Ok(Self {
+            cells,
+            bounds: (Pos::new(min.x, 0), max),
+            sand: 0,
+        })
+    }
+
+    fn simulate1(&mut self) -> bool {
+        if self.occupied(SOURCE) {
+            return true;
+        }
+        let mut sand_pos = SOURCE;
+
+        loop {
+            if !self.is_in_bounds(sand_pos) {
+                break true;
+            }
+
+            match self.fall(sand_pos, |p| self.occupied(p)) {
+                Some(p) => sand_pos = p,
+                None => {
+                    self.settle(sand_pos);
+                    break false;
+                }
+            }
+        }
+    }
+
+    fn simulate2(&mut self) -> bool {
+        if self.occupied(SOURCE) {
+            return true;
+        }
+        let mut sand_pos = SOURCE;
+
+        loop {
+            match self.fall(sand_pos, |p| self.occupied2(p)) {
+                Some(p) => sand_pos = p,
+                None => {
+                    self.settle(sand_pos);
+                    break false;
+                }
+            }
+        }
+    }
+
+    /// Where sand at `p` goes next, if it can move at all.
+    fn fall(&self, p: Pos, occupied: impl Fn(Pos) -> bool) -> Option<Pos> {
+        [Dir8::S, Dir8::SW, Dir8::SE]
+            .into_iter()
+            .filter_map(|dir| p.checked_add_signed(dir.into()))
+            .find(|&p| !occupied(p))
+    }
+
+    fn settle(&mut self, p: Pos) {
+        self.cells.insert(p, Material::Sand);
+        self.sand += 1;
+    }
+
+    fn is_in_bounds(&self, p: Pos) -> bool {
+        let (min, max) = self.bounds;
+        p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y
+    }
+
+    fn occupied(&self, p: Pos) -> bool {
+        self.cells.contains(p)
+    }
+
+    fn occupied2(&self, p: Pos) -> bool {
+        self.occupied(p) || p.y > (self.bounds.1.y + 1)
+    }
+
+    fn frame(&self) -> Frame {
+        let (min, max) = self.cells.bounds().unwrap();
+        Frame::grid(max.x - min.x + 1, self.bounds.1.y + 3, |p| {
+            match self.cells.get(Pos::new(min.x + p.x, p.y)) {
+                Some(Material::Rock) => ('#', Style::Solid),
+                Some(Material::Sand) => ('o', Style::Filled),
+                None => ('.', Style::Empty),
+            }
+        })
+        .animated()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Cave;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Cave::parse(&ParseContext::new(Self::DAY, input), input)
+    }
+
+    fn part1(&self, cave: &Self::Input<'_>) -> usize {
+        let mut cave = cave.clone();
+
+        while !cave.simulate1() {
+            viz::step(|| cave.frame());
+        }
+        viz::show(|| cave.frame());
+
+        cave.sand
+    }
+
+    fn part2(&self, cave: &Self::Input<'_>) -> usize {
+        let mut cave = cave.clone();
+
+        while !cave.simulate2() {
+            viz::step(|| cave.frame());
+        }
+        viz::show(|| cave.frame());
+
+        cave.sand
+    }
+
+    /// `size` paths of rock, turning up to three times each, in a cave that
+    /// gets deeper the more of them there are.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let depth = 10 + (size as f64).sqrt() as usize * 12;
+        let paths = (0..size.max(1))
+            .map(|_| {
+                let mut p = Pos::new(
+                    rng.range(SOURCE.x.saturating_sub(depth)..=SOURCE.x + depth),
+                    rng.range(2..=depth),
+                );
+                let mut points = vec![p];
+                for turn in 0..rng.range(1..=4) {
+                    let step = rng.range(1..=8);
+                    if turn % 2 == 0 {
+                        p.x = if rng.one_in(2) {
+                            p.x + step
+                        } else {
+                            p.x.saturating_sub(step)
+                        };
+                    } else {
+                        p.y = if rng.one_in(2) {
+                            p.y + step
+                        } else {
+                            p.y.saturating_sub(step).max(2)
+                        };
+                    }
+                    points.push(p);
+                }
+                points
+                    .iter()
+                    .map(|p| format!("{},{}", p.x, p.y))
+                    .join(" -> ")
+                    + "\n"
+            })
+            .collect();
+        Some(paths)
+    }
+}
+
+const SAMPLE01: &str = r#"
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+"#;
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day14.run1(SAMPLE01), 24);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day14.run2(SAMPLE01), 93);
+    }
+
+    #[test]
+    fn test_blocked_source() {
+        let cup = "499,1 -> 499,2 -> 501,2 -> 501,1";
+        assert_eq!(Day14.run1(cup), 2);
+        assert_eq!(Day14.run2(cup), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day14
+            .parse(&SAMPLE01.replace("498,6 -> 496,6", "498,6 -> 496,7"))
+            .unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (14, 2, 19));
+        assert_eq!(e.found, "496,7");
+    }
+
+    type Path = Vec<(usize, usize)>;
+
+    /// Grains of sand at rest once they start falling forever, or once one
+    /// blocks the source. With `floor`, there's an endless floor two below
+    /// the lowest rock instead of a void.
+    fn reference(paths: &[Path], floor: bool) -> usize {
+        let bottom = paths.iter().flatten().map(|&(_, y)| y).max().unwrap() + 2;
+        let mut blocked = vec![[false; 1000]; bottom + 1];
+        for path in paths {
+            for pair in path.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                for row in &mut blocked[y0.min(y1)..=y0.max(y1)] {
+                    row[x0.min(x1)..=x0.max(x1)].fill(true);
+                }
+            }
+        }
+        if floor {
+            blocked[bottom] = [true; 1000];
+        }
+
+        let mut grains = 0;
+        while !blocked[0][500] {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if y == bottom {
+                    return grains;
+                }
+                match [x, x - 1, x + 1].into_iter().find(|&x| !blocked[y + 1][x]) {
+                    Some(next) => (x, y) = (next, y + 1),
+                    None => break,
+                }
+            }
+            blocked[y][x] = true;
+            grains += 1;
+        }
+        grains
+    }
+
+    /// Paths of horizontal and vertical lines somewhere under the source.
+    fn paths() -> impl Strategy<Value = Vec<Path>> {
+        let point = (494..507usize, 1..10usize);
+        let step = (any::<bool>(), -4..=4isize);
+        let path = (point, vec(step, 1..4)).prop_map(|(start, steps)| {
+            steps
+                .into_iter()
+                .fold(vec![start], |mut path, (across, len)| {
+                    let (x, y) = *path.last().unwrap();
+                    path.push(if across {
+                        (x.saturating_add_signed(len), y)
+                    } else {
+                        (x, y.saturating_add_signed(len).max(1))
+                    });
+                    path
+                })
+        });
+        vec(path, 1..6)
+    }
+
+    proptest! {
+        #[test]
+        fn test_reference(paths in paths()) {
+            let input = paths
+                .iter()
+                .map(|path| path.iter().map(|(x, y)| format!("{x},{y}")).join(" -> ") + "\n")
+                .collect::<String>();
+            prop_assert_eq!(Day14.run1(&input), reference(&paths, false));
+            prop_assert_eq!(Day14.run2(&input), reference(&paths, true));
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use crate::{
+    geom::Point2,
+    interval::{Interval, RangeSet},
+    rng::Rng,
+    ParseContext, ParseError, Solution,
+};
+
+use nom::{bytes::complete::tag, character, combinator::all_consuming, sequence::tuple};
+
+/// A sensor, along with the closest beacon to it.
+type Reading = (Point2<i64>, Point2<i64>);
+
+fn parse(ctx: &ParseContext, input: &str) -> Result<Vec<Reading>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|l| {
+            let (_, (_, x, _, y, _, bx, _, by)) = all_consuming(tuple((
+                tag("Sensor at x="),
+                character::complete::i64,
+                tag(", y="),
+                character::complete::i64,
+                tag(": closest beacon is at x="),
+                character::complete::i64,
+                tag(", y="),
+                character::complete::i64,
+            )))(l)
+            .map_err(|e| {
+                ctx.nom_error(
+                    e,
+                    "a reading like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`",
+                )
+            })?;
+            Ok((Point2::new(x, y), Point2::new(bx, by)))
+        })
+        .collect()
+}
+
+/// Positions of `row` closer to a sensor than its beacon is.
+fn coverage(readings: &[Reading], row: i64) -> RangeSet<i64> {
+    readings
+        .iter()
+        .filter_map(|&(sensor, beacon)| {
+            let radius_x = sensor.manhattan(beacon) - (sensor.y - row).abs();
+            (radius_x >= 0).then(|| Interval::inclusive(sensor.x - radius_x, sensor.x + radius_x))
+        })
+        .collect()
+}
+
+pub struct Day15 {
+    /// Row scanned for beacon-free positions in part 1.
+    row: i64,
+    /// Upper bound of both coordinates of the distress beacon in part 2.
+    max: i64,
+}
+
+impl Day15 {
+    /// Row scanned in part 1 of the real input.
+    pub const ROW: i64 = 2000000;
+    /// Size of the search area in part 2 of the real input.
+    pub const MAX: i64 = 4000000;
+
+    /// Scans `row` in part 1 and searches coordinates from 0 to `max` in
+    /// part 2, or `None` if the search area is empty.
+    pub fn new(row: i64, max: i64) -> Option<Self> {
+        (max >= 0).then_some(Self { row, max })
+    }
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: Self::ROW,
+            max: Self::MAX,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<Reading>;
+    type Output1 = usize;
+    type Output2 = Option<i64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(&ParseContext::new(Self::DAY, input), input)
+    }
+
+    fn part1(&self, readings: &Self::Input<'_>) -> usize {
+        let covered = coverage(readings, self.row);
+        let beacons = readings
+            .iter()
+            .filter(|(_, beacon)| beacon.y == self.row && covered.contains(beacon.x))
+            .map(|(_, beacon)| beacon.x)
+            .collect::<HashSet<_>>();
+
+        covered.len() as usize - beacons.len()
+    }
+
+    fn part2(&self, readings: &Self::Input<'_>) -> Option<i64> {
+        let bounds = Interval::inclusive(0, self.max);
+        (0..=self.max).find_map(|y| {
+            let x = coverage(readings, y).gaps(bounds).next()?.start;
+            Some(4000000 * x + y)
+        })
+    }
+
+    /// `size` sensors hiding a single spot for the distress beacon. Four of
+    /// them sit diagonally away from it, just far enough that between them
+    /// they cover the rest of the search area, and the others reach short
+    /// of it.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let hole = Point2::new(rng.range(0..=self.max), rng.range(0..=self.max));
+        let d = self.max + rng.range(1..=self.max / 4 + 1);
+        let mut readings = [(1, 1), (-1, 1), (1, -1), (-1, -1)]
+            .map(|(sx, sy)| {
+                (
+                    hole + Point2::new(sx * d, sy * d),
+                    hole + Point2::new(0, sy),
+                )
+            })
+            .to_vec();
+
+        while readings.len() < size {
+            let sensor = Point2::new(rng.range(0..=self.max), rng.range(0..=self.max));
+            let reach = sensor.manhattan(hole) - 1;
+            if reach < 1 {
+                continue;
+            }
+            let radius = rng.range(1..=reach);
+            let dx = rng.range(0..=radius);
+            let (sx, sy) = (*rng.pick(&[-1, 1]), *rng.pick(&[-1, 1]));
+            readings.push((sensor, sensor + Point2::new(sx * dx, sy * (radius - dx))));
+        }
+        rng.shuffle(&mut readings);
+
+        Some(
+            readings
+                .iter()
+                .map(|(s, b)| {
+                    format!(
+                        "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                        s.x, s.y, b.x, b.y
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+const SAMPLE01: &str = r#"
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day15::new(10, 20).unwrap().run1(SAMPLE01), 26);
+    }
+
+    #[test]
+    fn test2() {
+        let day = Day15::new(10, 20).unwrap();
+        assert_eq!(day.run2(SAMPLE01), Some(56000011));
+        // Nowhere left for the distress beacon.
+        assert_eq!(Day15::new(10, 10).unwrap().run2(SAMPLE01), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day15::default()
+            .parse(&SAMPLE01.replace("x=13, y=2", "x=13 y=2"))
+            .unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (15, 4, 15));
+        assert_eq!(e.found, " y=2: closest beacon is at x=15, y=3");
+    }
+
+    #[test]
+    fn test_generate() {
+        let day = Day15::new(10, 20).unwrap();
+        for seed in 0..20 {
+            let input = day.generate(12, &mut Rng::new(seed)).unwrap();
+            let readings = day.parse(&input).unwrap();
+            assert_eq!(readings.len(), 12);
+
+            let mut uncovered = (0..=20).flat_map(|y| (0..=20).map(move |x| Point2::new(x, y)));
+            let hole = uncovered
+                .by_ref()
+                .find(|&p| {
+                    readings
+                        .iter()
+                        .all(|&(s, b)| s.manhattan(p) > s.manhattan(b))
+                })
+                .unwrap();
+            assert!(uncovered.all(|p| readings
+                .iter()
+                .any(|&(s, b)| s.manhattan(p) <= s.manhattan(b))));
+            assert_eq!(day.part2(&readings), Some(4000000 * hole.x + hole.y));
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{rng::Rng, ParseContext, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::{all_consuming, map},
+    multi::separated_list0,
+    sequence::tuple,
+    IResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Node<'a> {
+    name: &'a str,
+    flow: i64,
+    adj: Vec<&'a str>,
+}
+
+impl<'a> PartialOrd for Node<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Node<'a> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.flow.cmp(&other.flow)
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Node<'_>> {
+    map(
+        tuple((
+            tag("Valve "),
+            alpha1,
+            tag(" has flow rate="),
+            complete::i64,
+            alt((
+                tag("; tunnels lead to valves "),
+                tag("; tunnels lead to valve "),
+                tag("; tunnel leads to valves "),
+                tag("; tunnel leads to valve "),
+            )),
+            separated_list0(tag(", "), alpha1),
+        )),
+        |(_, name, _, flow, _, adj)| Node { name, flow, adj },
+    )(input)
+}
+
+fn parse<'a>(ctx: &ParseContext, input: &'a str) -> Result<HashMap<&'a str, Node<'a>>, ParseError> {
+    let nodes = input
+        .lines()
+        .map(|l| {
+            let (_, node) = all_consuming(parse_line)(l).map_err(|e| {
+                ctx.nom_error(
+                    e,
+                    "a valve like `Valve AA has flow rate=0; tunnels lead to valves DD, BB`",
+                )
+            })?;
+            Ok((node.name, node))
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    if let Some(adj) = nodes
+        .values()
+        .flat_map(|node| &node.adj)
+        .find(|adj| !nodes.contains_key(*adj))
+    {
+        return Err(ctx.error(adj, "the name of a valve in the input"));
+    }
+    if !nodes.contains_key("AA") {
+        return Err(ctx.error(&input[input.len()..], "a valve named `AA`"));
+    }
+
+    Ok(nodes)
+}
+
+fn dfs<'a>(
+    nodes: &HashMap<&'a str, Node<'a>>,
+    open_valves: &BTreeSet<&'a str>,
+    cache: &mut HashMap<String, i64>,
+    time_left: i64,
+    cur_node: &str,
+    elephant: bool,
+) -> i64 {
+    let cur_node = nodes.get(cur_node).unwrap();
+    let cache_key = format!(
+        "{}:{}:{}:{}",
+        open_valves.iter().fold(String::new(), |o, i| o + i),
+        cur_node.name,
+        time_left,
+        elephant
+    );
+
+    if let Some(v) = cache.get(&cache_key) {
+        return *v;
+    }
+
+    if time_left == 0 {
+        let flow = if elephant {
+            dfs(nodes, open_valves, cache, 26, "AA", false)
+        } else {
+            0
+        };
+        cache.insert(cache_key, flow);
+        return flow;
+    }
+
+    let mut flow = 0i64;
+    if !open_valves.contains(cur_node.name) && cur_node.flow > 0 {
+        // Open a valve and spend 1 minute if it is worth it to do so.
+        let mut open_valves = open_valves.clone();
+        open_valves.insert(cur_node.name);
+        let sub_flow = dfs(
+            nodes,
+            &open_valves,
+            cache,
+            time_left - 1,
+            cur_node.name,
+            elephant,
+        );
+        flow = i64::max(cur_node.flow * (time_left - 1) + sub_flow, flow);
+    }
+
+    // Move to another node.
+    let flow = cur_node.adj.iter().fold(flow, |o, edge| {
+        let sub_flow = dfs(nodes, open_valves, cache, time_left - 1, edge, elephant);
+        i64::max(sub_flow, o)
+    });
+
+    cache.insert(cache_key, flow);
+
+    flow
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = HashMap<&'a str, Node<'a>>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(&ParseContext::new(Self::DAY, input), input.trim())
+    }
+
+    fn part1(&self, nodes: &Self::Input<'_>) -> usize {
+        dfs(
+            nodes,
+            &Default::default(),
+            &mut Default::default(),
+            30,
+            "AA",
+            false,
+        ) as _
+    }
+
+    fn part2(&self, nodes: &Self::Input<'_>) -> usize {
+        dfs(
+            nodes,
+            &Default::default(),
+            &mut Default::default(),
+            26,
+            "AA",
+            true,
+        ) as _
+    }
+
+    /// `size` valves, all reachable from `AA`, with a quarter of them, and no
+    /// more than 15, letting any pressure out.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let size = size.max(2);
+        let letters = if size <= 26 * 26 { 2 } else { 3 };
+        let mut names = (0..26usize.pow(letters))
+            .map(|mut i| {
+                (0..letters)
+                    .map(|_| {
+                        let c = (b'A' + (i % 26) as u8) as char;
+                        i /= 26;
+                        c
+                    })
+                    .collect::<String>()
+            })
+            .filter(|name| name != "AA")
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        names.truncate(size - 1);
+        names.insert(0, "AA".to_string());
+
+        let mut flows = vec![0; size];
+        for flow in flows.iter_mut().skip(1).take((size / 4).clamp(1, 15)) {
+            *flow = rng.range(3..=25);
+        }
+        rng.shuffle(&mut flows[1..]);
+
+        let mut tunnels = vec![BTreeSet::new(); size];
+        let mut dig = |a: usize, b: usize| {
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        };
+        for i in 1..size {
+            dig(i, rng.range(0..i));
+        }
+        for _ in 0..size / 3 {
+            dig(rng.range(0..size), rng.range(0..size));
+        }
+
+        let mut lines = (0..size)
+            .map(|i| {
+                let adj = tunnels[i]
+                    .iter()
+                    .map(|&j| names[j].as_str())
+                    .collect::<Vec<_>>();
+                let tunnels = match adj.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={}; {tunnels} {}\n",
+                    names[i],
+                    flows[i],
+                    adj.join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        Some(lines.concat())
+    }
+}
+
+const SAMPLE01: &str = r#"
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day16.run1(SAMPLE01), 1651);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day16.run2(SAMPLE01), 1707);
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    cycle::fast_forward,
+    grid::{Pos, SparseGrid},
+    rng::Rng,
+    viz::{self, Frame, Style},
+    ParseContext, ParseError, Solution,
+};
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    Left,
+    Right,
+    Down,
+}
+
+fn parse(ctx: &ParseContext, input: &str) -> Result<Vec<Action>, ParseError> {
+    if input.is_empty() {
+        return Err(ctx.error(input, "a jet pattern of `<` and `>`"));
+    }
+    input
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Action::Left),
+            '>' => Ok(Action::Right),
+            _ => Err(ctx.error(&input[i..], "`<` or `>`")),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Shape {
+    Horiz,
+    Plus,
+    Corner,
+    Vert,
+    Square,
+}
+
+impl Shape {
+    fn size(&self) -> (usize, usize) {
+        match self {
+            Shape::Horiz => (4, 1),
+            Shape::Plus => (3, 3),
+            Shape::Corner => (3, 3),
+            Shape::Vert => (1, 4),
+            Shape::Square => (2, 2),
+        }
+    }
+
+    fn is_lit_bro(&self, x: usize, y: usize) -> bool {
+        match self {
+            Shape::Horiz => y == 0 && x <= 3,
+            Shape::Plus => [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)].contains(&(x, y)),
+            Shape::Corner => [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)].contains(&(x, y)),
+            Shape::Vert => x == 0 && y <= 3,
+            Shape::Square => x <= 1 && y <= 1,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Rock {
+    shape: Shape,
+    x: usize,
+    y: usize,
+}
+
+impl Rock {
+    fn action(&mut self, action: Action, chamber: &SparseGrid<Shape>) -> bool {
+        let mut this_next_pos = self.clone();
+        match action {
+            Action::Left => {
+                this_next_pos.x = self.x.saturating_sub(1);
+            }
+            Action::Right => {
+                this_next_pos.x = usize::min(7 - self.shape.size().0, self.x + 1);
+            }
+            Action::Down => {
+                if self.y == 0 {
+                    return false;
+                }
+                this_next_pos.y -= 1;
+            }
+        }
+
+        if this_next_pos.cells().any(|p| chamber.contains(p)) {
+            false
+        } else {
+            self.x = this_next_pos.x;
+            self.y = this_next_pos.y;
+            true
+        }
+    }
+
+    fn is_lit_bro(&self, (x, y): (usize, usize)) -> bool {
+        let (w, h) = self.shape.size();
+        if x < self.x || y < self.y || x > (self.x + w) || y > (self.y + h) {
+            false
+        } else {
+            let sx = x - self.x;
+            let sy = y - self.y;
+            self.shape.is_lit_bro(sx, sy)
+        }
+    }
+
+    /// Positions in the chamber taken by the rock.
+    fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        let (w, h) = self.shape.size();
+        (0..h)
+            .flat_map(move |y| (0..w).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.shape.is_lit_bro(x, y))
+            .map(|(x, y)| Pos::new(self.x + x, self.y + y))
+    }
+}
+
+/// Rocks fall in this order, over and over.
+const SHAPES: [Shape; 5] = [
+    Shape::Horiz,
+    Shape::Plus,
+    Shape::Corner,
+    Shape::Vert,
+    Shape::Square,
+];
+
+/// Rows at the top of the tower telling chambers apart. Rocks don't fall
+/// further than this into any tower they leave behind.
+const STATE_ROWS: usize = 64;
+
+struct Chamber<'a> {
+    rocks: SparseGrid<Shape>,
+    actions: &'a [Action],
+    /// Index of the next jet of gas in `actions`.
+    next_action: usize,
+    /// Number of rocks dropped so far.
+    count: usize,
+    /// Height of each column.
+    tops: [usize; 7],
+}
+
+impl<'a> Chamber<'a> {
+    fn new(actions: &'a [Action]) -> Self {
+        Self {
+            rocks: SparseGrid::new(),
+            actions,
+            next_action: 0,
+            count: 0,
+            tops: [0; 7],
+        }
+    }
+
+    /// Height of the tower of settled rocks.
+    fn height(&self) -> usize {
+        self.rocks.bounds().map_or(0, |(_, max)| max.y + 1)
+    }
+
+    fn drop_rock(&mut self) {
+        let shape = SHAPES[self.count % SHAPES.len()];
+        let mut rock = Rock {
+            shape,
+            x: 2,
+            y: self.height() + 3,
+        };
+
+        loop {
+            let action = self.actions[self.next_action];
+            self.next_action = (self.next_action + 1) % self.actions.len();
+
+            rock.action(action, &self.rocks);
+            if !rock.action(Action::Down, &self.rocks) {
+                break;
+            }
+
+            viz::step(|| self.frame(&rock));
+        }
+
+        for p in rock.cells() {
+            self.rocks.insert(p, shape);
+            self.tops[p.x] = usize::max(self.tops[p.x], p.y + 1);
+        }
+        self.count += 1;
+    }
+
+    /// Everything deciding where the next rocks land: the next rock and jet,
+    /// and the rows from the lowest column up, one bit per cell, as nothing
+    /// can fall further down than that. Only the top [`STATE_ROWS`] rows are
+    /// kept, so that a column that stops growing doesn't keep the state from
+    /// ever repeating.
+    fn state(&self) -> (usize, usize, Vec<u8>) {
+        let floor = usize::max(
+            self.tops.into_iter().min().unwrap(),
+            self.height().saturating_sub(STATE_ROWS),
+        );
+        let rows = (floor..self.height())
+            .map(|y| {
+                (0..7)
+                    .filter(|&x| self.rocks.contains(Pos::new(x, y)))
+                    .fold(0, |row, x| row | 1 << x)
+            })
+            .collect();
+        (self.count % SHAPES.len(), self.next_action, rows)
+    }
+
+    /// The chamber with `rock` falling in it, top row first.
+    fn frame(&self, rock: &Rock) -> Frame {
+        let top = usize::max(self.height(), rock.y + rock.shape.size().1);
+
+        Frame::grid(9, top + 1, |p| {
+            let (x, y) = (p.x.wrapping_sub(1), top - p.y);
+            if x >= 7 {
+                ('|', Style::Plain)
+            } else if self.rocks.contains(Pos::new(x, y)) {
+                ('#', Style::Solid)
+            } else if rock.is_lit_bro((x, y)) {
+                ('@', Style::Filled)
+            } else {
+                ('.', Style::Empty)
+            }
+        })
+        .title(format!("Rock {}", self.count + 1))
+        .animated()
+    }
+}
+
+/// Height of the tower after `count` rocks fell.
+fn simulate(actions: &[Action], count: usize) -> usize {
+    fast_forward(
+        &mut Chamber::new(actions),
+        count,
+        Chamber::drop_rock,
+        Chamber::state,
+        Chamber::height,
+    )
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<Action>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(&ParseContext::new(Self::DAY, input), input.trim())
+    }
+
+    fn part1(&self, actions: &Self::Input<'_>) -> usize {
+        simulate(actions, 2022)
+    }
+
+    fn part2(&self, actions: &Self::Input<'_>) -> usize {
+        simulate(actions, 1000000000000)
+    }
+
+    /// A jet pattern `size` long.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        Some((0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect())
+    }
+}
+
+const SAMPLE01: &str = r#">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day17.run1(SAMPLE01), 3068);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day17.run2(SAMPLE01), 1514285714288);
+    }
+
+    #[test]
+    fn test_stalled_column() {
+        // Jets that keep every rock away from some column, which then never
+        // grows, still give a tower that repeats.
+        for jets in [">", "<>", ">><<<", "<><>>><>><><<"] {
+            let actions = Day17.parse(jets).unwrap();
+            let mut chamber = Chamber::new(&actions);
+            for _ in 0..5000 {
+                chamber.drop_rock();
+            }
+            assert_eq!(simulate(&actions, 5000), chamber.height(), "{jets}");
+            assert!(Day17.run2(jets) > 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day17.parse(">><x").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (17, 1, 4));
+        assert!(Day17.parse("\n").is_err());
+    }
+}
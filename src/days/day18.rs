@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+use crate::{geom::Point3, rng::Rng, search::bfs, ParseContext, ParseError, Solution};
+use log::debug;
+
+type Cube = Point3<i32>;
+
+fn parse_cube(ctx: &ParseContext, line: &str) -> Result<Cube, ParseError> {
+    match line.split(',').collect::<Vec<_>>()[..] {
+        [x, y, z] => Ok(Cube::new(ctx.number(x)?, ctx.number(y)?, ctx.number(z)?)),
+        _ => Err(ctx.error(line, "a cube like `2,2,2`")),
+    }
+}
+
+pub struct CubeGraph(Vec<Cube>);
+
+impl CubeGraph {
+    fn parse(ctx: &ParseContext, input: &str) -> Result<Self, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|l| parse_cube(ctx, l))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn count_blocked_faces(&self) -> usize {
+        let cubes = self.0.iter().copied().collect::<HashSet<_>>();
+        self.0
+            .iter()
+            .map(|c| c.neighbours6().filter(|n| !cubes.contains(n)).count())
+            .sum()
+    }
+
+    /// Faces of the cubes that the outside air reaches, flooding it in
+    /// from a corner of a box one wider than the cubes.
+    fn count_exterior_faces(&self) -> usize {
+        let Some(&first) = self.0.first() else {
+            return 0;
+        };
+        let (min, max) = self.0.iter().fold((first, first), |(min, max), c| {
+            (
+                Cube::new(min.x.min(c.x), min.y.min(c.y), min.z.min(c.z)),
+                Cube::new(max.x.max(c.x), max.y.max(c.y), max.z.max(c.z)),
+            )
+        });
+        let (min, max) = (min - Cube::new(1, 1, 1), max + Cube::new(1, 1, 1));
+        debug!("bounds {min} to {max}");
+
+        let inside = |c: Cube| {
+            (min.x..=max.x).contains(&c.x)
+                && (min.y..=max.y).contains(&c.y)
+                && (min.z..=max.z).contains(&c.z)
+        };
+        let cubes = self.0.iter().copied().collect::<HashSet<_>>();
+        let air = bfs([min], |c: Cube| {
+            c.neighbours6()
+                .filter(|&n| inside(n) && !cubes.contains(&n))
+                .collect::<Vec<_>>()
+        });
+
+        air.iter()
+            .map(|(c, _)| c.neighbours6().filter(|n| cubes.contains(n)).count())
+            .sum()
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = CubeGraph;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        CubeGraph::parse(&ParseContext::new(Self::DAY, input), input)
+    }
+
+    fn part1(&self, g: &Self::Input<'_>) -> usize {
+        g.count_blocked_faces()
+    }
+
+    fn part2(&self, g: &Self::Input<'_>) -> usize {
+        g.count_exterior_faces()
+    }
+
+    /// `size` distinct cubes, filling about half of a box big enough for
+    /// twice as many.
+    fn generate(&self, size: usize, rng: &mut Rng) -> Option<String> {
+        let side = (2.0 * size.max(1) as f64).cbrt().ceil() as i32;
+        let mut seen = HashSet::new();
+        let mut input = String::new();
+        while seen.len() < size.max(1) {
+            let cube = Cube::new(rng.range(0..side), rng.range(0..side), rng.range(0..side));
+            if seen.insert(cube) {
+                input += &format!("{},{},{}\n", cube.x, cube.y, cube.z);
+            }
+        }
+        Some(input)
+    }
+}
+
+const SAMPLE01: &str = r#"
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+"#;
+
+#[cfg(test)]
+mod tests {
+    use itertools::iproduct;
+    use proptest::{collection::hash_set, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day18.run1(SAMPLE01), 64);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day18.run2(SAMPLE01), 58);
+    }
+
+    const SIZE: i32 = 6;
+
+    /// Faces of the cubes touching something that isn't a cube, or only the
+    /// outside air if `exterior`. Air is marked as outside by sweeping the
+    /// box around the cubes until nothing changes.
+    fn reference(cubes: &HashSet<(i32, i32, i32)>, exterior: bool) -> usize {
+        let range = -1..=SIZE;
+        let mut outside = HashSet::new();
+        loop {
+            let before = outside.len();
+            for (x, y, z) in iproduct!(range.clone(), range.clone(), range.clone()) {
+                let at_edge = [x, y, z].iter().any(|&c| c == -1 || c == SIZE);
+                let next_to_outside = [(1, 0, 0), (0, 1, 0), (0, 0, 1)]
+                    .iter()
+                    .flat_map(|&(dx, dy, dz)| [(x + dx, y + dy, z + dz), (x - dx, y - dy, z - dz)])
+                    .any(|c| outside.contains(&c));
+                if !cubes.contains(&(x, y, z)) && (at_edge || next_to_outside) {
+                    outside.insert((x, y, z));
+                }
+            }
+            if outside.len() == before {
+                break;
+            }
+        }
+
+        cubes
+            .iter()
+            .flat_map(|&(x, y, z)| {
+                [
+                    (x + 1, y, z),
+                    (x - 1, y, z),
+                    (x, y + 1, z),
+                    (x, y - 1, z),
+                    (x, y, z + 1),
+                    (x, y, z - 1),
+                ]
+            })
+            .filter(|c| !cubes.contains(c) && (!exterior || outside.contains(c)))
+            .count()
+    }
+
+    fn cubes() -> impl Strategy<Value = HashSet<(i32, i32, i32)>> {
+        hash_set((0..SIZE, 0..SIZE, 0..SIZE), 1..160)
+    }
+
+    proptest! {
+        #[test]
+        fn test_reference(cubes in cubes()) {
+            let input = cubes
+                .iter()
+                .map(|(x, y, z)| format!("{x},{y},{z}\n"))
+                .collect::<String>();
+            prop_assert_eq!(Day18.run1(&input), reference(&cubes, false));
+            prop_assert_eq!(Day18.run2(&input), reference(&cubes, true));
+        }
+    }
+}
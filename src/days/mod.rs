@@ -0,0 +1,65 @@
+use crate::Runner;
+
+macro_rules! days {
+    ($($day:literal => $module:ident: $solution:expr),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every implemented day, in order.
+        pub fn all() -> Vec<Box<dyn Runner>> {
+            vec![$(Box::new($solution)),*]
+        }
+
+        /// The solver for `day`, if it has been implemented.
+        pub fn get(day: u8) -> Option<Box<dyn Runner>> {
+            match day {
+                $($day => Some(Box::new($solution)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod template;
+
+days! {
+    1 => day01: day01::Day01,
+    2 => day02: day02::Day02::default(),
+    3 => day03: day03::Day03::default(),
+    4 => day04: day04::Day04,
+    5 => day05: day05::Day05,
+    6 => day06: day06::Day06,
+    7 => day07: day07::Day07,
+    8 => day08: day08::Day08,
+    9 => day09: day09::Day09,
+    10 => day10: day10::Day10,
+    11 => day11: day11::Day11,
+    12 => day12: day12::Day12,
+    13 => day13: day13::Day13,
+    14 => day14: day14::Day14,
+    15 => day15: day15::Day15::default(),
+    16 => day16: day16::Day16,
+    17 => day17: day17::Day17,
+    18 => day18: day18::Day18,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for runner in all() {
+            let day = runner.day();
+            let input = runner
+                .generate(30, 1)
+                .unwrap_or_else(|| panic!("day {day} has no generator"));
+            assert_eq!(runner.generate(30, 1), Some(input.clone()), "day {day}");
+            assert_ne!(runner.generate(30, 2), Some(input.clone()), "day {day}");
+            // Part 2 of some days takes long however small the input.
+            runner
+                .run(&input, Some(1))
+                .unwrap_or_else(|e| panic!("day {day}: {e}"));
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{ParseError, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = 0;
+    const SAMPLE: &'static str = SAMPLE01;
+
+    type Input<'a> = Vec<&'a str>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Ok(input.trim().lines().collect())
+    }
+
+    fn part1(&self, _lines: &Self::Input<'_>) -> usize {
+        0
+    }
+
+    fn part2(&self, _lines: &Self::Input<'_>) -> usize {
+        0
+    }
+}
+
+const SAMPLE01: &str = r#"
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(Day00.run1(SAMPLE01), 0);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(Day00.run2(SAMPLE01), 0);
+    }
+}
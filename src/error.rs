@@ -0,0 +1,131 @@
+use std::{fmt, io, path::PathBuf, time::Duration};
+
+use crate::ParseError;
+
+#[derive(Debug)]
+pub enum Error {
+    /// No input file exists for the day.
+    MissingInput {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Config {
+        path: PathBuf,
+        message: String,
+    },
+    /// The puzzle input is malformed.
+    Parse(ParseError),
+    /// Neither the environment nor the config file provide a session cookie.
+    MissingSession,
+    /// The request failed before a response came back.
+    Http(String),
+    HttpStatus {
+        status: u16,
+        body: String,
+    },
+    /// The server answered with a page we can't make sense of.
+    UnexpectedResponse(String),
+    /// The server rejected the session cookie.
+    NotLoggedIn,
+    NotFound {
+        day: u8,
+    },
+    /// The part was solved already, so there's nothing to submit.
+    AlreadySolved {
+        day: u8,
+        part: u8,
+        answer: String,
+    },
+    /// The ledger shows the answer can't be right.
+    KnownWrong {
+        answer: String,
+        reason: String,
+    },
+    /// The server asked to wait before submitting again.
+    Throttled {
+        day: u8,
+        remaining: Duration,
+    },
+    /// Scaffolding a day that is already there.
+    DayExists {
+        day: u8,
+        path: PathBuf,
+    },
+    /// The puzzle for the day hasn't unlocked yet.
+    Locked {
+        day: u8,
+        remaining: Duration,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput { day, path } => write!(
+                f,
+                "no input for day {day}: {} doesn't exist (download it there, or pass --input)",
+                path.display()
+            ),
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Config { path, message } => write!(f, "{}: {message}", path.display()),
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::MissingSession => write!(
+                f,
+                "no session cookie: set $AOC_SESSION or `session` in aoc.toml"
+            ),
+            Error::Http(e) => write!(f, "request failed: {e}"),
+            Error::HttpStatus { status, body } => {
+                write!(f, "unexpected HTTP {status}: {}", body.trim())
+            }
+            Error::UnexpectedResponse(body) => {
+                write!(f, "unexpected response from the server:\n{}", body.trim())
+            }
+            Error::NotLoggedIn => {
+                write!(f, "not logged in: the session cookie is invalid or expired")
+            }
+            Error::NotFound { day } => write!(f, "there is no puzzle for day {day}"),
+            Error::AlreadySolved { day, part, answer } => {
+                write!(f, "day {day} part {part} was already solved with {answer}")
+            }
+            Error::KnownWrong { answer, reason } => {
+                write!(f, "not submitting {answer}: {reason}")
+            }
+            Error::Throttled { day, remaining } => write!(
+                f,
+                "wait {} before submitting day {day} again",
+                fmt_remaining(*remaining)
+            ),
+            Error::DayExists { day, path } => {
+                write!(f, "day {day} already exists in {}", path.display())
+            }
+            Error::Locked { day, remaining } => {
+                write!(f, "day {day} unlocks in {}", fmt_remaining(*remaining))
+            }
+        }
+    }
+}
+
+fn fmt_remaining(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    format!("{}h {:02}m {:02}s", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Numbers points can be made of.
+pub trait Coord:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Coord for T where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+}
+
+/// Coordinates that can go below zero, and so can be moved in every
+/// direction.
+pub trait Signed: Coord + Neg<Output = Self> + From<i8> {}
+
+impl<T> Signed for T where T: Coord + Neg<Output = T> + From<i8> {}
+
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn signum<T: Signed>(a: T) -> T {
+    T::from(match a.cmp(&T::default()) {
+        std::cmp::Ordering::Less => -1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => 1,
+    })
+}
+
+/// A point on a plane. On maps read from text, `y` grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A point in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+}
+
+impl<T: Signed> Point2<T> {
+    /// Each coordinate replaced by its sign, so a step of at most one
+    /// towards where the point points.
+    pub fn signum(self) -> Self {
+        Self::new(signum(self.x), signum(self.y))
+    }
+
+    /// The orthogonal neighbours, clockwise from above.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |dir| self + dir.into())
+    }
+
+    /// The orthogonal and diagonal neighbours, clockwise from above.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |dir| self + dir.into())
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    pub fn signum(self) -> Self {
+        Self::new(signum(self.x), signum(self.y), signum(self.z))
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::from(1), T::default());
+        [
+            Self::new(one, zero, zero),
+            Self::new(-one, zero, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, zero, one),
+            Self::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |delta| self + delta)
+    }
+}
+
+impl Point2<usize> {
+    /// The point moved by `delta`, unless that takes it below zero.
+    pub fn checked_add_signed(self, delta: Point2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($c:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),* }
+            }
+        }
+
+        impl<T: Add<Output = T> + Copy> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Sub<Output = T> + Copy> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// One of the four orthogonal directions on a map, with `Up` towards
+/// smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction a quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the eight compass directions on a map, with `N` towards smaller
+/// `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from `N`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl<T: Signed> From<Dir4> for Point2<T> {
+    /// The step one square towards `dir`.
+    fn from(dir: Dir4) -> Self {
+        Dir8::from(dir).into()
+    }
+}
+
+impl<T: Signed> From<Dir8> for Point2<T> {
+    /// The step one square towards `dir`.
+    fn from(dir: Dir8) -> Self {
+        let (x, y) = match dir {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        };
+        Self::new(T::from(x), T::from(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!(a * 3, Point2::new(9, -6));
+        assert_eq!((a - b).signum(), Point2::new(1, -1));
+
+        let mut c = Point3::new(1i64, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c -= Point3::new(0, 0, 5);
+        assert_eq!(c, Point3::new(2, 3, -1));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(3, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point2::new(2usize, 7).manhattan(Point2::new(5, 1)), 9);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::new(2, 2, 1)), 3);
+        assert_eq!(Point3::new(1, 2, 3).chebyshev(Point3::new(2, 2, 1)), 2);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+        assert_eq!(Dir8::NW.turn_right(), Dir8::N);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::S);
+        assert_eq!(Point2::<i32>::from(Dir4::Up), Point2::new(0, -1));
+        assert_eq!(Point2::<i32>::from(Dir8::SW), Point2::new(-1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(0, 0);
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p.neighbours8().all(|n| n.chebyshev(p) == 1));
+        assert!(Point3::new(0, 0, 0)
+            .neighbours6()
+            .all(|n| n.manhattan(Point3::default()) == 1));
+        assert_eq!(
+            Point2::new(0usize, 3).checked_add_signed(Point2::new(0, -3)),
+            Some(Point2::new(0, 0))
+        );
+        assert_eq!(
+            Point2::new(0usize, 3).checked_add_signed(Point2::new(-1, 0)),
+            None
+        );
+    }
+}
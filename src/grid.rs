@@ -0,0 +1,464 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geom::{Dir4, Dir8, Point2},
+    ParseContext, ParseError,
+};
+
+/// Position of a cell, with `y` growing downwards in parsed maps.
+pub type Pos = Point2<usize>;
+
+/// A dense, rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the cells don't fill a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "ragged grid"
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses a map with one character per cell, such as the ones of most
+    /// grid puzzles. `cell` turns each character into a cell, or rejects it
+    /// with `None`, in which case the error says `expected` was expected.
+    ///
+    /// Every line must be as long as the first one, and there must be at
+    /// least one cell.
+    pub fn parse(
+        ctx: &ParseContext,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let (mut width, mut height) = (0, 0);
+
+        for line in input.lines() {
+            let mut count = 0;
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| ctx.error(&line[i..], expected))?);
+                count += 1;
+            }
+
+            if height > 0 && count != width {
+                return Err(ctx.error(line, format!("a row {width} cells wide")));
+            }
+            width = count;
+            height += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ctx.error(input, expected));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.x + pos.y * self.width])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.in_bounds(pos)
+            .then(|| &mut self.cells[pos.x + pos.y * self.width])
+    }
+
+    /// The cell at `pos`, wrapping around the edges in both directions as
+    /// if the grid was tiled infinitely.
+    pub fn get_wrapping(&self, pos: Point2<isize>) -> &T {
+        let x = pos.x.rem_euclid(self.width as isize) as usize;
+        let y = pos.y.rem_euclid(self.height as isize) as usize;
+        &self[Pos::new(x, y)]
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Pos::new(i % width, i / width))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| pred(c)).map(|(pos, _)| pos)
+    }
+
+    /// `pos` moved by `delta`, if it stays inside the grid.
+    pub fn step(&self, pos: Pos, delta: impl Into<Point2<isize>>) -> Option<Pos> {
+        pos.checked_add_signed(delta.into())
+            .filter(|&pos| self.in_bounds(pos))
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid, clockwise from
+    /// above.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid,
+    /// clockwise from above.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Positions from `pos`, excluded, moving by `delta` until the edge of
+    /// the grid.
+    pub fn ray(&self, pos: Pos, delta: impl Into<Point2<isize>>) -> impl Iterator<Item = Pos> + '_ {
+        let delta = delta.into();
+        std::iter::successors(self.step(pos, delta), move |&pos| self.step(pos, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Builds a new grid from positions in the new one, `at` telling which
+    /// cell of `self` goes there.
+    fn remap(&self, width: usize, height: usize, at: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: (0..width * height)
+                .map(|i| self[at(Pos::new(i % width, i / width))].clone())
+                .collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |p| Pos::new(p.y, p.x))
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |p| Pos::new(p.y, height - 1 - p.x))
+    }
+
+    /// The grid turned a quarter counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |p| Pos::new(width - 1 - p.y, p.x))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A grid that only stores the cells that were set, for boards that are
+/// mostly empty or have no fixed size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<(Pos, Pos)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top-left and bottom-right corners, both included, of the smallest
+    /// rectangle holding every cell.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets the cell at `pos`, returning what was there before.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Pos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Pos::new(max.x.max(pos.x), max.y.max(pos.y)),
+            ),
+            None => (pos, pos),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// The orthogonal neighbours of `pos`, set or not.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| pos.checked_add_signed(dir.into()))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos`, set or not.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| pos.checked_add_signed(dir.into()))
+    }
+
+    /// A dense copy of the area within the bounds, with `fill` where no cell
+    /// is set, along with the position its top-left corner had.
+    pub fn to_dense(&self, fill: T) -> Option<(Pos, Grid<T>)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds?;
+        let mut grid = Grid::new(max.x - min.x + 1, max.y - min.y + 1, fill);
+        for (&pos, cell) in &self.cells {
+            grid[pos - min] = cell.clone();
+        }
+        Some((min, grid))
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, cell) in iter {
+            grid.insert(pos, cell);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: usize, y: usize) -> Pos {
+        Pos::new(x, y)
+    }
+
+    const MAP: &str = "123\n456";
+
+    fn grid() -> Grid<u32> {
+        let ctx = ParseContext::new(0, MAP);
+        Grid::parse(&ctx, MAP, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[p(2, 1)], 6);
+        assert_eq!(grid.to_string(), MAP);
+
+        let input = "12\n3x\n";
+        let ctx = ParseContext::new(0, input);
+        let e = Grid::parse(&ctx, input, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 2, "a digit"));
+
+        let input = "12\n345\n";
+        let ctx = ParseContext::new(0, input);
+        let e = Grid::parse(&ctx, input, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let ctx = ParseContext::new(0, "");
+        let e = Grid::parse(&ctx, "", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (1, 1, "a digit"));
+    }
+
+    #[test]
+    fn test_access() {
+        let grid = grid();
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(grid.get(p(0, 2)), None);
+        assert_eq!(grid.get_wrapping(Point2::new(-1, 0)), &3);
+        assert_eq!(grid.get_wrapping(Point2::new(3, 3)), &4);
+        assert_eq!(grid.position(|&c| c > 4), Some(p(1, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4(p(0, 0)).collect::<Vec<_>>(),
+            [p(1, 0), p(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(p(1, 1)).collect::<Vec<_>>(),
+            [p(1, 0), p(2, 0), p(2, 1), p(0, 1), p(0, 0)]
+        );
+        assert_eq!(
+            grid.ray(p(0, 1), Dir4::Right).collect::<Vec<_>>(),
+            [p(1, 1), p(2, 1)]
+        );
+        assert_eq!(grid.ray(p(0, 1), Dir4::Down).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(
+            grid.columns().map(|c| c.sum::<u32>()).collect::<Vec<_>>(),
+            [5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_cw().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_ccw().to_string(), "36\n25\n14");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c * 2)[p(1, 0)], 4);
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut sparse = SparseGrid::new();
+        assert_eq!(sparse.bounds(), None);
+        sparse.insert(p(5, 2), 'a');
+        sparse.insert(p(3, 4), 'b');
+        assert_eq!(sparse.bounds(), Some((p(3, 2), p(5, 4))));
+        assert!(sparse.contains(p(3, 4)));
+        assert_eq!(sparse.get(p(4, 4)), None);
+        assert_eq!(sparse.neighbours4(p(0, 0)).count(), 2);
+
+        let (origin, dense) = sparse.to_dense('.').unwrap();
+        assert_eq!(origin, p(3, 2));
+        assert_eq!(dense.to_string(), "..a\n...\nb..");
+    }
+}
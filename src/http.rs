@@ -0,0 +1,151 @@
+use std::io::Read;
+
+use crate::Error;
+
+/// User agent sent with every request, as asked for by the Advent of Code
+/// automation guidelines.
+pub const USER_AGENT: &str = "github.com/veeenu/adventofcode2022";
+
+/// A completed HTTP exchange, whatever its status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP interface the Advent of Code client needs, so that tests can
+/// point it at a local server or replace it altogether.
+pub trait HttpClient {
+    /// Performs a `GET` with the `session` cookie set.
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error>;
+
+    /// Performs a `POST` of an url-encoded form with the `session` cookie set.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)])
+        -> Result<Response, Error>;
+}
+
+/// [`HttpClient`] backed by `ureq`.
+pub struct UreqClient(ureq::Agent);
+
+impl UreqClient {
+    pub fn new() -> Self {
+        Self(ureq::AgentBuilder::new().user_agent(USER_AGENT).build())
+    }
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error> {
+        let request = self.0.get(url).set("Cookie", &format!("session={session}"));
+        into_response(request.call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Error> {
+        let request = self
+            .0
+            .post(url)
+            .set("Cookie", &format!("session={session}"));
+        into_response(request.send_form(form))
+    }
+}
+
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, Error> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => return Err(Error::Http(e.to_string())),
+    };
+
+    let status = response.status();
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|e| Error::Http(e.to_string()))?;
+
+    Ok(Response { status, body })
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::{SocketAddr, TcpListener},
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A local HTTP server answering each connection with the next canned
+    /// `(status, body)` pair, and recording the requests it receives.
+    pub struct MockServer {
+        addr: SocketAddr,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MockServer {
+        pub fn start(responses: Vec<(u16, String)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let addr = listener.local_addr().unwrap();
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut BufReader::new(&mut stream));
+                    recorded.lock().unwrap().push(request);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            Self { addr, requests }
+        }
+
+        pub fn url(&self) -> String {
+            format!("http://{}", self.addr)
+        }
+
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> String {
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                content_length = len.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        request.push_str(&String::from_utf8(body).unwrap());
+
+        request
+    }
+}
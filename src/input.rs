@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+/// Where puzzle inputs are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// One `dayNN.txt` file per day inside a directory.
+    Dir(PathBuf),
+    /// A single file, whatever the day.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Directory used when neither a flag nor [`Self::DIR_VAR`] says otherwise.
+    pub const DEFAULT_DIR: &'static str = "inputs";
+    /// Environment variable overriding the inputs directory.
+    pub const DIR_VAR: &'static str = "AOC_INPUTS";
+
+    /// The inputs directory named by `AOC_INPUTS`, or `inputs/`.
+    pub fn from_env() -> Self {
+        InputSource::Dir(Self::default_dir())
+    }
+
+    /// Path of the directory [`Self::from_env`] reads from.
+    pub fn default_dir() -> PathBuf {
+        env::var_os(Self::DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(Self::DEFAULT_DIR))
+    }
+
+    /// Interprets a command line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &Path) -> Self {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_owned())
+        }
+    }
+
+    /// Path of the input file for `day` inside `dir`.
+    pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+        dir.join(format!("day{day:02}.txt"))
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, Error> {
+        match self {
+            InputSource::Dir(dir) => {
+                let path = Self::day_path(dir, day);
+                if !path.exists() {
+                    return Err(Error::MissingInput { day, path });
+                }
+                read_file(&path)
+            }
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::Io {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    })?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day01.txt"), "1000\n").unwrap();
+
+        let source = InputSource::Dir(dir.clone());
+        assert_eq!(source.load(1).unwrap(), "1000\n");
+        assert!(matches!(
+            source.load(2),
+            Err(Error::MissingInput { day: 2, path }) if path == dir.join("day02.txt")
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(Path::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Path::new("foo.txt")),
+            InputSource::File(PathBuf::from("foo.txt"))
+        );
+    }
+}
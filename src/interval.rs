@@ -0,0 +1,280 @@
+use std::fmt;
+
+use crate::geom::Coord;
+
+/// The values from `start` included to `end` excluded. Empty if `end` isn't
+/// past `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Coord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The values from `first` to `last`, both included, the way puzzles
+    /// usually write ranges.
+    pub fn inclusive(first: T, last: T) -> Self
+    where
+        T: From<u8>,
+    {
+        Self::new(first, last + T::from(1))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of values in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in this one too.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether there's a value in both.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end && !self.is_empty() && !other.is_empty()
+    }
+
+    /// The values in both, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let both = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!both.is_empty()).then_some(both)
+    }
+
+    /// The values in this interval but not in `other`, which may be split in
+    /// two pieces, lowest first.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let (below, above) = if self.overlaps(other) {
+            (
+                Self::new(self.start, other.start),
+                Self::new(other.end, self.end),
+            )
+        } else {
+            (*self, Self::new(self.end, self.end))
+        };
+        [below, above].into_iter().filter(|i| !i.is_empty())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of values kept as the fewest intervals covering them, sorted and
+/// with gaps between each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Coord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |len, i| len + i.len())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let at = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(at).is_some_and(|i| i.contains(value))
+    }
+
+    /// The intervals making up the set, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// Adds the values of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[lo..hi].iter().fold(interval, |merged, i| {
+            Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+        });
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Takes the values of `interval` out, splitting the intervals it falls
+    /// inside of.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let lo = self.intervals.partition_point(|i| i.end <= interval.start);
+        let hi = self.intervals.partition_point(|i| i.start < interval.end);
+        let pieces = self.intervals[lo..hi]
+            .iter()
+            .flat_map(|i| i.difference(&interval))
+            .collect::<Vec<_>>();
+        self.intervals.splice(lo..hi, pieces);
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for i in other.iter() {
+            union.insert(i);
+        }
+        union
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for i in other.iter() {
+            difference.remove(i);
+        }
+        difference
+    }
+
+    /// The intervals within `bounds` holding none of the values in the set,
+    /// lowest first.
+    pub fn gaps(&self, bounds: Interval<T>) -> impl Iterator<Item = Interval<T>> + '_ {
+        let mut from = bounds.start;
+        self.intervals
+            .iter()
+            .map(Some)
+            .chain([None])
+            .map_while(move |i| {
+                if from >= bounds.end {
+                    return None;
+                }
+                let (gap_end, next) = match i {
+                    Some(i) => (i.start.min(bounds.end), from.max(i.end)),
+                    None => (bounds.end, bounds.end),
+                };
+                let gap = Interval::new(from, gap_end);
+                from = next;
+                Some(gap)
+            })
+            .filter(|gap| !gap.is_empty())
+    }
+}
+
+impl<T: Coord> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn i(start: i32, end: i32) -> Interval<i32> {
+        Interval::new(start, end)
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(Interval::inclusive(2, 4), i(2, 5));
+        assert_eq!(i(2, 5).len(), 3);
+        assert_eq!(i(5, 2).len(), 0);
+        assert!(i(2, 5).contains(4));
+        assert!(!i(2, 5).contains(5));
+
+        assert!(i(2, 8).covers(&i(3, 7)));
+        assert!(!i(3, 7).covers(&i(2, 8)));
+        assert!(i(2, 5).overlaps(&i(4, 6)));
+        assert!(!i(2, 5).overlaps(&i(5, 6)));
+
+        assert_eq!(i(2, 5).intersection(&i(4, 6)), Some(i(4, 5)));
+        assert_eq!(i(2, 5).intersection(&i(5, 6)), None);
+
+        assert_eq!(
+            i(0, 10).difference(&i(3, 5)).collect::<Vec<_>>(),
+            [i(0, 3), i(5, 10)]
+        );
+        assert_eq!(
+            i(0, 10).difference(&i(-3, 5)).collect::<Vec<_>>(),
+            [i(5, 10)]
+        );
+        assert_eq!(
+            i(0, 10).difference(&i(12, 15)).collect::<Vec<_>>(),
+            [i(0, 10)]
+        );
+        assert_eq!(i(0, 10).difference(&i(-1, 11)).count(), 0);
+    }
+
+    #[test]
+    fn test_range_set() {
+        let mut set = [i(10, 12), i(0, 3), i(5, 7), i(3, 4)]
+            .into_iter()
+            .collect::<RangeSet<_>>();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [i(0, 4), i(5, 7), i(10, 12)]
+        );
+        assert_eq!(set.len(), 8);
+        assert!(set.contains(6));
+        assert!(!set.contains(4));
+
+        set.insert(i(6, 10));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [i(0, 4), i(5, 12)]);
+
+        set.remove(i(2, 6));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [i(0, 2), i(6, 12)]);
+
+        let other = [i(-2, 1), i(11, 20)].into_iter().collect();
+        assert_eq!(
+            set.union(&other).iter().collect::<Vec<_>>(),
+            [i(-2, 2), i(6, 20)]
+        );
+        assert_eq!(
+            set.difference(&other).iter().collect::<Vec<_>>(),
+            [i(1, 2), i(6, 11)]
+        );
+
+        assert_eq!(
+            set.gaps(i(-5, 20)).collect::<Vec<_>>(),
+            [i(-5, 0), i(2, 6), i(12, 20)]
+        );
+        assert_eq!(set.gaps(i(1, 8)).collect::<Vec<_>>(), [i(2, 6)]);
+        assert_eq!(RangeSet::new().gaps(i(1, 8)).collect::<Vec<_>>(), [i(1, 8)]);
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Judgement, Verdict},
+    http::HttpClient,
+    Error,
+};
+
+/// One answer sent to the server, and what it made of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time the answer was sent at.
+    pub at: u64,
+    /// Unix time before which the server won't take another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+/// Every answer ever submitted, right or wrong, kept in `ledger.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    pub const FILE: &'static str = "ledger.toml";
+
+    /// Reads the ledger at `path`, or starts an empty one if there is none.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let text = toml::to_string(self).map_err(|e| Error::Config {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, text).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    fn of(&self, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    /// Checks, without contacting the server, whether `answer` is worth
+    /// sending.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<(), Error> {
+        if let Some(right) = self.of(day, part).find(|s| s.verdict == Verdict::Right) {
+            return Err(Error::AlreadySolved {
+                day,
+                part,
+                answer: right.answer.clone(),
+            });
+        }
+
+        let now = unix_time(now);
+        let wait_until = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day)
+            .filter_map(|s| s.wait_until)
+            .max();
+        if let Some(wait_until) = wait_until.filter(|&w| w > now) {
+            return Err(Error::Throttled {
+                day,
+                remaining: Duration::from_secs(wait_until - now),
+            });
+        }
+
+        for s in self.of(day, part).filter(|s| s.verdict.is_wrong()) {
+            if s.answer == answer {
+                return Err(Error::KnownWrong {
+                    answer: answer.to_string(),
+                    reason: "it was already rejected".to_string(),
+                });
+            }
+
+            // Numeric answers can also be ruled out by earlier hints.
+            let bound = match (answer.parse::<i64>(), s.answer.parse::<i64>()) {
+                (Ok(a), Ok(b)) => (a, b),
+                _ => continue,
+            };
+            let reason = match (s.verdict, bound) {
+                (Verdict::TooHigh, (a, b)) if a >= b => format!("{b} was already too high"),
+                (Verdict::TooLow, (a, b)) if a <= b => format!("{b} was already too low"),
+                _ => continue,
+            };
+            return Err(Error::KnownWrong {
+                answer: answer.to_string(),
+                reason,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        judgement: Judgement,
+        now: SystemTime,
+    ) {
+        let at = unix_time(now);
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: judgement.verdict,
+            at,
+            wait_until: judgement.wait.map(|w| at + w.as_secs()),
+        });
+    }
+
+    /// Sends `answer` unless the ledger rules it out, and records the outcome.
+    pub fn submit<H: HttpClient>(
+        &mut self,
+        client: &Client<H>,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: SystemTime,
+    ) -> Result<Judgement, Error> {
+        self.check(day, part, answer, now)?;
+        let judgement = client.submit(day, part, answer)?;
+        self.record(day, part, answer, judgement, now);
+        Ok(judgement)
+    }
+}
+
+fn unix_time(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{mock::MockServer, UreqClient};
+
+    const RIGHT: &str = "<p>That's the right answer!  You are one gold star closer.</p>";
+    const WRONG: &str =
+        "<p>That's not the right answer.  Please wait one minute before trying again.</p>";
+    const TOO_HIGH: &str = "<p>That's not the right answer; your answer is too high.  \
+        Please wait one minute before trying again.</p>";
+    const TOO_LOW: &str = "<p>That's not the right answer; your answer is too low.  \
+        Please wait one minute before trying again.</p>";
+    const TOO_RECENT: &str = "<p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 30s left to wait.</p>";
+
+    fn client(server: &MockServer) -> Client<UreqClient> {
+        Client::with_base_url(UreqClient::new(), "cafe".to_string(), server.url())
+    }
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000 + secs)
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(
+            [TOO_HIGH, TOO_RECENT, TOO_LOW, WRONG, RIGHT]
+                .map(|body| (200, body.to_string()))
+                .to_vec(),
+        );
+        let client = client(&server);
+        let mut ledger = Ledger::default();
+
+        let verdict = |ledger: &mut Ledger, answer, now| {
+            ledger.submit(&client, 1, 1, answer, now).map(|j| j.verdict)
+        };
+
+        assert_eq!(
+            verdict(&mut ledger, "500", at(0)).unwrap(),
+            Verdict::TooHigh
+        );
+        assert!(matches!(
+            verdict(&mut ledger, "200", at(30)),
+            Err(Error::Throttled { remaining, .. }) if remaining == Duration::from_secs(30)
+        ));
+        assert_eq!(
+            verdict(&mut ledger, "200", at(60)).unwrap(),
+            Verdict::TooRecent
+        );
+        assert_eq!(
+            verdict(&mut ledger, "200", at(100)).unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict(&mut ledger, "300", at(200)).unwrap(),
+            Verdict::Wrong
+        );
+
+        // None of these reach the server.
+        assert!(matches!(
+            verdict(&mut ledger, "300", at(300)),
+            Err(Error::KnownWrong { reason, .. }) if reason == "it was already rejected"
+        ));
+        assert!(matches!(
+            verdict(&mut ledger, "600", at(300)),
+            Err(Error::KnownWrong { reason, .. }) if reason == "500 was already too high"
+        ));
+        assert!(matches!(
+            verdict(&mut ledger, "150", at(300)),
+            Err(Error::KnownWrong { reason, .. }) if reason == "200 was already too low"
+        ));
+
+        assert_eq!(
+            verdict(&mut ledger, "400", at(300)).unwrap(),
+            Verdict::Right
+        );
+        assert!(matches!(
+            verdict(&mut ledger, "401", at(400)),
+            Err(Error::AlreadySolved { answer, .. }) if answer == "400"
+        ));
+
+        assert_eq!(server.requests().len(), 5);
+        assert_eq!(ledger.submissions().len(), 5);
+        assert_eq!(ledger.submissions()[1].wait_until, Some(1_700_000_090));
+    }
+
+    #[test]
+    fn test_save_load() {
+        let path = std::env::temp_dir().join(format!("aoc-ledger-{}.toml", std::process::id()));
+        let mut ledger = Ledger::default();
+        ledger.record(
+            5,
+            1,
+            "CMZ",
+            Judgement {
+                verdict: Verdict::Right,
+                wait: None,
+            },
+            at(0),
+        );
+        ledger.save(&path).unwrap();
+
+        let loaded = Ledger::load(&path).unwrap();
+        assert_eq!(loaded.submissions(), ledger.submissions());
+
+        fs::remove_file(path).unwrap();
+    }
+}
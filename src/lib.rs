@@ -0,0 +1,33 @@
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+mod config;
+pub mod cycle;
+pub mod days;
+mod error;
+pub mod geom;
+pub mod grid;
+pub mod http;
+mod input;
+pub mod interval;
+pub mod ledger;
+pub mod logging;
+mod parse;
+pub mod record;
+pub mod render;
+pub mod rng;
+mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod select;
+mod solution;
+pub mod viz;
+
+pub use answer::{Answer, Screen};
+pub use config::Config;
+pub use error::Error;
+pub use input::InputSource;
+pub use parse::{ParseContext, ParseError};
+pub use runner::{PartReport, Report, Runner};
+pub use solution::Solution;
@@ -0,0 +1,150 @@
+use std::{collections::HashMap, env, io::Write, str::FromStr};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Which log events to keep: up to one level for every day, with exceptions
+/// for some days.
+///
+/// Written like `info,11=trace,day13=debug`: a bare level applies to
+/// everything, and `DAY=LEVEL` to that day only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    days: HashMap<u8, LevelFilter>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: LevelFilter::Warn,
+            days: HashMap::new(),
+        }
+    }
+}
+
+impl Filter {
+    /// Environment variable holding the filter when no flag gives one.
+    pub const VAR: &'static str = "AOC_LOG";
+
+    /// The filter in [`Self::VAR`], or only warnings and errors if it isn't
+    /// set.
+    pub fn from_env() -> Result<Self, String> {
+        match env::var(Self::VAR) {
+            Ok(spec) => spec.parse().map_err(|e| format!("bad ${}: {e}", Self::VAR)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// The most verbose level kept for any day.
+    pub fn max_level(&self) -> LevelFilter {
+        self.days.values().copied().fold(self.default, Ord::max)
+    }
+
+    /// The level kept for events from `target`.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        day_of(target)
+            .and_then(|day| self.days.get(&day))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let level = |s: &str| {
+            s.parse::<LevelFilter>()
+                .map_err(|_| format!("`{s}` is not a level like `info` or `trace`"))
+        };
+
+        let mut filter = Self::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((day, lvl)) => {
+                    let day = day
+                        .trim_start_matches("day")
+                        .parse()
+                        .ok()
+                        .filter(|day| (1..=25).contains(day))
+                        .ok_or_else(|| format!("`{day}` is not a day like `11` or `day11`"))?;
+                    filter.days.insert(day, level(lvl)?);
+                }
+                None => filter.default = level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// The day a module path like `adventofcode2022::days::day11` belongs to.
+fn day_of(target: &str) -> Option<u8> {
+    let (_, module) = target.rsplit_once("::day")?;
+    module.parse().ok()
+}
+
+/// Prints the events `filter` keeps on stderr, out of the way of answers.
+struct Logger {
+    filter: Filter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let target = record.target();
+        let source = target.rsplit_once("::").map_or(target, |(_, last)| last);
+        let _ = writeln!(
+            std::io::stderr().lock(),
+            "{:<5} {source}: {}",
+            record.level(),
+            record.args()
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+/// Sends log events through `filter` to stderr from now on. Only the first
+/// call has any effect.
+pub fn init(filter: Filter) {
+    let max_level = filter.max_level();
+    if log::set_boxed_logger(Box::new(Logger { filter })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = "info, 11=trace,day06=off".parse::<Filter>().unwrap();
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+        assert_eq!(
+            filter.level("adventofcode2022::days::day11"),
+            LevelFilter::Trace
+        );
+        assert_eq!(
+            filter.level("adventofcode2022::days::day06"),
+            LevelFilter::Off
+        );
+        assert_eq!(
+            filter.level("adventofcode2022::days::day08"),
+            LevelFilter::Info
+        );
+        assert_eq!(filter.level("adventofcode2022::bench"), LevelFilter::Info);
+
+        assert_eq!("".parse::<Filter>(), Ok(Filter::default()));
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("26=trace".parse::<Filter>().is_err());
+        assert!("day=trace".parse::<Filter>().is_err());
+    }
+}
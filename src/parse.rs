@@ -0,0 +1,141 @@
+use std::{fmt, str::FromStr};
+
+/// Why a puzzle input couldn't be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// What was being parsed: `input`, or something else a day reads, such
+    /// as `rules`.
+    pub subject: &'static str,
+    /// Line of the input the error is on, counting from 1.
+    pub line: usize,
+    /// Column in characters, counting from 1.
+    pub column: usize,
+    /// The text that couldn't be parsed, up to the end of its line. Empty if
+    /// the line or the input ended too early.
+    pub found: String,
+    /// What the parser was looking for instead.
+    pub expected: String,
+    /// The whole of the line the error is on.
+    pub source_line: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {}, line {}, column {}: expected {}, ",
+            self.day, self.subject, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            writeln!(f, "found nothing")?;
+        } else {
+            writeln!(f, "found {:?}", self.found)?;
+        }
+
+        let gutter = self.line.to_string().len();
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:gutter$} | {:>col$}", "", "^", col = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Builds [`ParseError`]s pointing into the input of a day.
+///
+/// Errors are located from the position of the offending text in the input,
+/// so parsers can just pass along the slice they choked on.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseContext<'a> {
+    day: u8,
+    subject: &'static str,
+    input: &'a str,
+}
+
+impl<'a> ParseContext<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Self {
+            day,
+            subject: "input",
+            input,
+        }
+    }
+
+    /// Labels errors as being about `subject` rather than the puzzle input.
+    pub fn subject(self, subject: &'static str) -> Self {
+        Self { subject, ..self }
+    }
+
+    /// An error at the start of `at`, which should be a slice of the input.
+    /// Anything else is reported at the end of the input.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.input.len())
+            .unwrap_or(self.input.len());
+
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            day: self.day,
+            subject: self.subject,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: at.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+            source_line: self.input[line_start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    /// Parses the whole of `s` as a number.
+    pub fn number<T: FromStr>(&self, s: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, "a number"))
+    }
+
+    /// Turns the error of a nom parser run over the input into a
+    /// [`ParseError`].
+    pub fn nom_error(
+        &self,
+        e: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => self.error(e.input, expected),
+            nom::Err::Incomplete(_) => self.error(&self.input[self.input.len()..], expected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "1 2\n3 x 4\n";
+        let ctx = ParseContext::new(7, input);
+
+        let e = ctx.number::<u8>(&input[6..7]).unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (7, 2, 3));
+        assert_eq!(e.found, "x");
+        assert_eq!(e.source_line, "3 x 4");
+        assert_eq!(
+            e.to_string(),
+            "day 7 input, line 2, column 3: expected a number, found \"x\"\n  |\n2 | 3 x 4\n  |   ^"
+        );
+
+        let e = ctx.error(&input[input.len()..], "a line");
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.found, "");
+
+        let e = ctx.error("elsewhere", "a line");
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+}
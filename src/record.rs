@@ -0,0 +1,193 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{Answer, Report};
+
+/// One part of one day's run, flattened for scripts to read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// The answer as text. For screens, the letters on them, if they could
+    /// be read.
+    pub answer: Option<String>,
+    pub answer_type: AnswerType,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+    /// SHA-256 of the input, in hex.
+    pub input_hash: String,
+    /// For screens, the rows of pixels drawn with `#` and `.`.
+    pub pixels: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnswerType {
+    Number,
+    Text,
+    Screen,
+    /// The input has no answer.
+    None,
+}
+
+impl AnswerType {
+    fn as_str(self) -> &'static str {
+        match self {
+            AnswerType::Number => "number",
+            AnswerType::Text => "text",
+            AnswerType::Screen => "screen",
+            AnswerType::None => "none",
+        }
+    }
+}
+
+impl Record {
+    /// A record per part in `report`, which was run on `input`.
+    pub fn from_report(report: &Report, input: &str) -> Vec<Self> {
+        let input_hash = input_hash(input);
+        report
+            .parts
+            .iter()
+            .map(|part| {
+                let (answer, answer_type, pixels) = match &part.answer {
+                    Answer::Number(n) => (Some(n.to_string()), AnswerType::Number, None),
+                    Answer::Text(s) => (Some(s.clone()), AnswerType::Text, None),
+                    Answer::Screen(s) => (
+                        s.decode(),
+                        AnswerType::Screen,
+                        Some(s.to_string().lines().map(String::from).collect()),
+                    ),
+                    Answer::NotFound => (None, AnswerType::None, None),
+                };
+                Record {
+                    day: report.day,
+                    part: part.part,
+                    answer,
+                    answer_type,
+                    parse_time_ns: report.parse_time.as_nanos() as u64,
+                    solve_time_ns: part.time.as_nanos() as u64,
+                    input_hash: input_hash.clone(),
+                    pixels,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Hex SHA-256 of a puzzle input, to tell which input a record is about
+/// without sharing the input itself.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Writes `records` as a JSON array.
+pub fn write_json(mut out: impl Write, records: &[Record]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut out, records)?;
+    writeln!(out)
+}
+
+/// Writes `records` as CSV with a header, one line each. The pixels of a
+/// screen are its rows joined with `/`, and missing values are empty.
+pub fn write_csv(out: impl Write, records: &[Record]) -> io::Result<()> {
+    let mut csv = csv::Writer::from_writer(out);
+    csv.write_record([
+        "day",
+        "part",
+        "answer",
+        "answer_type",
+        "parse_time_ns",
+        "solve_time_ns",
+        "input_hash",
+        "pixels",
+    ])?;
+    for r in records {
+        csv.write_record([
+            &r.day.to_string(),
+            &r.part.to_string(),
+            r.answer.as_deref().unwrap_or(""),
+            r.answer_type.as_str(),
+            &r.parse_time_ns.to_string(),
+            &r.solve_time_ns.to_string(),
+            &r.input_hash,
+            &r.pixels
+                .as_ref()
+                .map(|rows| rows.join("/"))
+                .unwrap_or_default(),
+        ])?;
+    }
+    csv.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{PartReport, Screen};
+
+    fn records() -> Vec<Record> {
+        let report = Report {
+            day: 10,
+            parse_time: Duration::from_micros(3),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: 13140.into(),
+                    time: Duration::from_nanos(1500),
+                },
+                PartReport {
+                    part: 2,
+                    answer: Screen::new(2, vec![true, false, false, true]).into(),
+                    time: Duration::from_nanos(20),
+                },
+            ],
+        };
+        Record::from_report(&report, "noop\n")
+    }
+
+    #[test]
+    fn test_records() {
+        let records = records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].answer.as_deref(), Some("13140"));
+        assert_eq!(records[0].parse_time_ns, 3000);
+        assert_eq!(records[0].solve_time_ns, 1500);
+        assert_eq!(records[1].answer, None);
+        assert_eq!(records[1].answer_type, AnswerType::Screen);
+        assert_eq!(records[1].pixels, Some(vec!["#.".into(), ".#".into()]));
+        assert_eq!(records[0].input_hash, records[1].input_hash);
+        assert_eq!(
+            input_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &records()).unwrap();
+        let json = serde_json::from_slice::<serde_json::Value>(&out).unwrap();
+        assert_eq!(json[0]["answer"], "13140");
+        assert_eq!(json[0]["answer_type"], "number");
+        assert_eq!(json[0]["pixels"], serde_json::Value::Null);
+        assert_eq!(json[1]["pixels"][1], ".#");
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &records()).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("day,part,answer,answer_type,"));
+        assert!(lines[1].starts_with("10,1,13140,number,3000,1500,"));
+        assert!(lines[2].starts_with("10,2,,screen,3000,20,"));
+        assert!(lines[2].ends_with(",#./.#"));
+    }
+}
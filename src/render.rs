@@ -0,0 +1,287 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::viz::{Frame, Style, Visualizer};
+
+/// The colour of each style, as red, green and blue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: [[u8; 3]; Style::ALL.len()],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new()
+            .with(Style::Plain, [200, 200, 200])
+            .with(Style::Dim, [80, 80, 90])
+            .with(Style::Good, [80, 200, 90])
+            .with(Style::Bad, [220, 60, 60])
+            .with(Style::Lit, [255, 240, 150])
+            .with(Style::Solid, [110, 80, 60])
+            .with(Style::Filled, [230, 190, 90])
+            .with(Style::Empty, [15, 15, 25])
+    }
+}
+
+impl Palette {
+    /// Every style black.
+    pub fn new() -> Self {
+        Self {
+            colours: [[0; 3]; Style::ALL.len()],
+        }
+    }
+
+    pub fn with(mut self, style: Style, rgb: [u8; 3]) -> Self {
+        self.colours[style as usize] = rgb;
+        self
+    }
+
+    pub fn colour(&self, style: Style) -> [u8; 3] {
+        self.colours[style as usize]
+    }
+
+    /// The colours one after the other, the way image formats store palettes.
+    fn flat(&self) -> Vec<u8> {
+        self.colours.concat()
+    }
+}
+
+/// A frame as pixels, each a square of `scale` by `scale` per character.
+/// Pixels hold the index of their style in the [`Palette`], and blanks are
+/// [`Style::Empty`]. Titles are left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    pub fn new(frame: &Frame, scale: usize) -> Self {
+        let cells = frame
+            .lines()
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .flat_map(|span| span.text.chars().map(move |c| (c, span.style)))
+                    .map(|(c, style)| if c == ' ' { Style::Empty } else { style } as u8)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut raster = Self::blank(columns * scale, cells.len() * scale);
+        for (y, row) in cells.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                for dy in 0..scale {
+                    let start = (y * scale + dy) * raster.width + x * scale;
+                    raster.pixels[start..start + scale].fill(cell);
+                }
+            }
+        }
+        raster
+    }
+
+    fn blank(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Style::Empty as u8; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The style of the pixel at `x`, `y`.
+    pub fn get(&self, x: usize, y: usize) -> Option<Style> {
+        (x < self.width && y < self.height)
+            .then(|| Style::ALL[self.pixels[y * self.width + x] as usize])
+    }
+
+    /// The raster grown to `width` by `height`, with the new pixels blank.
+    fn pad(&self, width: usize, height: usize) -> Self {
+        let mut padded = Self::blank(width.max(self.width), height.max(self.height));
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            let start = y * padded.width;
+            padded.pixels[start..start + row.len()].copy_from_slice(row);
+        }
+        padded
+    }
+}
+
+/// Writes each frame as a PNG image to its own numbered file.
+pub struct Png {
+    dir: PathBuf,
+    palette: Palette,
+    scale: usize,
+    count: usize,
+}
+
+impl Png {
+    pub fn new(dir: impl Into<PathBuf>, palette: Palette, scale: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            palette,
+            scale,
+            count: 0,
+        })
+    }
+}
+
+impl Visualizer for Png {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        let raster = Raster::new(frame, self.scale);
+        if raster.pixels.is_empty() {
+            return Ok(());
+        }
+
+        self.count += 1;
+        let path = self.dir.join(format!("frame{:05}.png", self.count));
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(File::create(path)?),
+            raster.width as u32,
+            raster.height as u32,
+        );
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(self.palette.flat());
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&raster.pixels))
+            .map_err(io::Error::other)
+    }
+}
+
+/// Collects the frames into one looping animated GIF, written once they're
+/// all in since its size has to fit the largest of them.
+pub struct Gif {
+    out: Option<BufWriter<File>>,
+    palette: Palette,
+    scale: usize,
+    /// Time each frame stays up.
+    delay: Duration,
+    frames: Vec<Raster>,
+}
+
+impl Gif {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        palette: Palette,
+        scale: usize,
+        delay: Duration,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            out: Some(BufWriter::new(File::create(path.into())?)),
+            palette,
+            scale,
+            delay,
+            frames: Vec::new(),
+        })
+    }
+}
+
+impl Visualizer for Gif {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        let raster = Raster::new(frame, self.scale);
+        if !raster.pixels.is_empty() {
+            self.frames.push(raster);
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let Some(out) = self.out.take() else {
+            return Ok(());
+        };
+        let width = self.frames.iter().map(Raster::width).max().unwrap_or(1);
+        let height = self.frames.iter().map(Raster::height).max().unwrap_or(1);
+        let size =
+            |n: usize| u16::try_from(n).map_err(|_| io::Error::other("frames too large for a GIF"));
+
+        let mut encoder = gif::Encoder::new(out, size(width)?, size(height)?, &self.palette.flat())
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX.into()) as u16;
+        for raster in self.frames.drain(..) {
+            let raster = raster.pad(width, height);
+            let mut frame =
+                gif::Frame::from_indexed_pixels(width as u16, height as u16, raster.pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let mut frame = Frame::grid(3, 2, |p| {
+            if p.x == p.y {
+                ('#', Style::Solid)
+            } else {
+                ('.', Style::Empty)
+            }
+        });
+        frame.push(' ', Style::Lit).push('o', Style::Filled);
+        frame.title("step")
+    }
+
+    #[test]
+    fn test_raster() {
+        let raster = Raster::new(&frame(), 2);
+        assert_eq!((raster.width(), raster.height()), (6, 6));
+        assert_eq!(raster.get(1, 1), Some(Style::Solid));
+        assert_eq!(raster.get(2, 1), Some(Style::Empty));
+        assert_eq!(raster.get(3, 3), Some(Style::Solid));
+        assert_eq!(raster.get(1, 4), Some(Style::Empty));
+        assert_eq!(raster.get(3, 5), Some(Style::Filled));
+        assert_eq!(raster.get(5, 5), Some(Style::Empty));
+        assert_eq!(raster.get(6, 0), None);
+
+        let padded = raster.pad(8, 7);
+        assert_eq!((padded.width(), padded.height()), (8, 7));
+        assert_eq!(padded.get(3, 3), Some(Style::Solid));
+        assert_eq!(padded.get(7, 0), Some(Style::Empty));
+    }
+
+    #[test]
+    fn test_images() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let mut png = Png::new(&dir, Palette::default(), 2).unwrap();
+        png.show(&frame()).unwrap();
+        png.show(&Frame::new().title("nothing to draw")).unwrap();
+        assert!(fs::read(dir.join("frame00001.png"))
+            .unwrap()
+            .starts_with(b"\x89PNG"));
+        assert!(!dir.join("frame00002.png").exists());
+
+        let path = dir.join("anim.gif");
+        let mut gif = Gif::new(&path, Palette::default(), 1, Duration::from_millis(50)).unwrap();
+        gif.show(&frame()).unwrap();
+        gif.show(&Frame::grid(5, 1, |_| ('#', Style::Lit))).unwrap();
+        gif.finish().unwrap();
+        let gif = fs::read(&path).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        // Sized to fit both frames.
+        assert_eq!(gif[6..10], [5, 0, 3, 0]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
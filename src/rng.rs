@@ -0,0 +1,115 @@
+use std::ops::{Bound, RangeBounds};
+
+/// A small seedable pseudo-random number generator (SplitMix64). It gives
+/// the same numbers for the same seed on every platform and with every
+/// version of the crate's dependencies, which generated inputs rely on.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number below `n`, or 0 if `n` is 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Sample>(&mut self, range: impl RangeBounds<T>) -> T {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s.to_i128(),
+            Bound::Excluded(&s) => s.to_i128() + 1,
+            Bound::Unbounded => T::MIN.to_i128(),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.to_i128() + 1,
+            Bound::Excluded(&e) => e.to_i128(),
+            Bound::Unbounded => T::MAX.to_i128() + 1,
+        };
+        assert!(start < end, "empty range");
+        T::from_i128(start + self.below((end - start) as u64) as i128)
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(..items.len())]
+    }
+
+    /// Puts `items` in a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(..=i));
+        }
+    }
+}
+
+/// Integers [`Rng::range`] can pick from.
+pub trait Sample: Copy {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn to_i128(self) -> i128;
+    fn from_i128(n: i128) -> Self;
+}
+
+macro_rules! sample {
+    ($($t:ty),*) => {
+        $(impl Sample for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(n: i128) -> Self {
+                n as $t
+            }
+        })*
+    };
+}
+
+sample!(u8, u32, u64, usize, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3i64..=3)));
+            assert!((10..12).contains(&rng.range(10usize..12)));
+        }
+        assert_eq!(rng.range(5u8..=5), 5);
+
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant};
+
+use crate::{rng::Rng, Answer, ParseError, Solution};
+
+/// Type-erased view of a [`Solution`], so that days with different input and
+/// answer types can be driven from the same place.
+pub trait Runner {
+    fn day(&self) -> u8;
+    fn sample(&self) -> &'static str;
+
+    /// Parses `input` and solves the requested part, or both if `part` is
+    /// `None`.
+    fn run(&self, input: &str, part: Option<u8>) -> Result<Report, ParseError>;
+
+    /// A random input of the given `size`, the same every time for the same
+    /// `seed`. See [`Solution::generate`].
+    fn generate(&self, size: usize, seed: u64) -> Option<String>;
+}
+
+/// Answers and timings of a single run of a day.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+impl Report {
+    pub fn part(&self, part: u8) -> Option<&PartReport> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|p| p.time).sum::<Duration>()
+    }
+}
+
+impl<S: Solution> Runner for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn sample(&self) -> &'static str {
+        S::SAMPLE
+    }
+
+    fn run(&self, input: &str, part: Option<u8>) -> Result<Report, ParseError> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse_time = start.elapsed();
+
+        let mut parts = Vec::with_capacity(2);
+
+        if part.unwrap_or(1) == 1 {
+            let start = Instant::now();
+            let answer = self.part1(&input).into();
+            parts.push(PartReport {
+                part: 1,
+                answer,
+                time: start.elapsed(),
+            });
+        }
+
+        if part.unwrap_or(2) == 2 {
+            let start = Instant::now();
+            let answer = self.part2(&input).into();
+            parts.push(PartReport {
+                part: 2,
+                answer,
+                time: start.elapsed(),
+            });
+        }
+
+        Ok(Report {
+            day: S::DAY,
+            parse_time,
+            parts,
+        })
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        Solution::generate(self, size, &mut Rng::new(seed))
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, InputSource};
+
+/// Source of every new day. It is compiled along with the tests, so whatever
+/// it generates is known to build.
+const TEMPLATE: &str = include_str!("days/template.rs");
+
+/// Directory of the day modules, relative to the root of the crate.
+pub const DAYS_DIR: &str = "src/days";
+
+/// Files written for a new day.
+#[derive(Debug)]
+pub struct Scaffold {
+    pub module: PathBuf,
+    /// `None` if an input was already there.
+    pub input: Option<PathBuf>,
+}
+
+/// Adds a module for `day` to the crate rooted at `root`, registers it and
+/// creates an empty input for it in `inputs`, unless one is there already.
+///
+/// Refuses to touch a day that already exists.
+pub fn create(
+    root: &Path,
+    inputs: &Path,
+    day: u8,
+    sample: Option<&str>,
+) -> Result<Scaffold, Error> {
+    let dir = root.join(DAYS_DIR);
+    let module = dir.join(format!("day{day:02}.rs"));
+    let registry = dir.join("mod.rs");
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    };
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|source| Error::Io {
+            path: path.to_owned(),
+            source,
+        })
+    };
+
+    if module.exists() {
+        return Err(Error::DayExists { day, path: module });
+    }
+    let days = register(&read(&registry)?, day).ok_or_else(|| Error::DayExists {
+        day,
+        path: registry.clone(),
+    })?;
+
+    write(&module, &render(day, sample))?;
+    write(&registry, &days)?;
+
+    let input = InputSource::day_path(inputs, day);
+    let input = if input.exists() {
+        None
+    } else {
+        fs::create_dir_all(inputs).map_err(|source| Error::Io {
+            path: inputs.to_owned(),
+            source,
+        })?;
+        write(&input, "")?;
+        Some(input)
+    };
+
+    Ok(Scaffold { module, input })
+}
+
+/// The template, turned into the module for `day`.
+pub fn render(day: u8, sample: Option<&str>) -> String {
+    let module = TEMPLATE
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"));
+
+    match sample {
+        Some(sample) => {
+            let sample = sample.trim_matches('\n');
+            // Enough hashes for the raw string not to end inside the sample.
+            let hashes = "#".repeat(
+                (1..)
+                    .find(|&n| !sample.contains(&format!("\"{}", "#".repeat(n))))
+                    .unwrap(),
+            );
+            module.replace(
+                "const SAMPLE01: &str = r#\"\n\"#;",
+                &format!("const SAMPLE01: &str = r{hashes}\"\n{sample}\n\"{hashes};"),
+            )
+        }
+        None => module,
+    }
+}
+
+/// Adds `day` to the `days!` list in the source of `src/days/mod.rs`, keeping
+/// it sorted. Returns `None` if the day is listed already.
+pub fn register(registry: &str, day: u8) -> Option<String> {
+    let entry = format!("    {day} => day{day:02}: day{day:02}::Day{day:02},\n");
+
+    let start = registry.find("days! {\n")? + "days! {\n".len();
+    let end = start + registry[start..].find("}\n")?;
+
+    let mut at = end;
+    let mut offset = start;
+    for line in registry[start..end].split_inclusive('\n') {
+        let listed = line.trim().split(' ').next()?.parse::<u8>().ok()?;
+        if listed == day {
+            return None;
+        }
+        if listed > day {
+            at = offset;
+            break;
+        }
+        offset += line.len();
+    }
+
+    let mut registry = registry.to_string();
+    registry.insert_str(at, &entry);
+    Some(registry)
+}
+
+/// The first `<pre><code>` block of a puzzle page, which is where the example
+/// input usually is.
+pub fn extract_sample(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+
+    let mut sample = String::new();
+    let mut rest = &html[start..end];
+    while let Some(tag) = rest.find('<') {
+        sample.push_str(&rest[..tag]);
+        rest = rest[tag..].split_once('>').map_or("", |(_, rest)| rest);
+    }
+    sample.push_str(rest);
+
+    Some(
+        sample
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::Runner;
+
+days! {
+    1 => day01: day01::Day01,
+    3 => day03: day03::Day03,
+}
+";
+
+    #[test]
+    fn test_render() {
+        let module = render(7, None);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("assert_eq!(Day07.run1(SAMPLE01), 0);"));
+        assert!(!module.contains("00"));
+
+        let module = render(7, Some("\n$ cd /\n$ ls\n"));
+        assert!(module.contains("const SAMPLE01: &str = r#\"\n$ cd /\n$ ls\n\"#;"));
+
+        let module = render(7, Some("a\"#b"));
+        assert!(module.contains("const SAMPLE01: &str = r##\"\na\"#b\n\"##;"));
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = register(REGISTRY, 2).unwrap();
+        assert!(registry.contains(
+            "    1 => day01: day01::Day01,\n    2 => day02: day02::Day02,\n    3 => day03: day03::Day03,\n}"
+        ));
+
+        let registry = register(REGISTRY, 12).unwrap();
+        assert!(
+            registry.contains("    3 => day03: day03::Day03,\n    12 => day12: day12::Day12,\n}")
+        );
+
+        assert_eq!(register(REGISTRY, 3), None);
+    }
+
+    #[test]
+    fn test_extract_sample() {
+        let html =
+            "<p>For example:</p>\n<pre><code>[1,[2,<em>3</em>]]\n-&gt; &amp;\n</code></pre>\n\
+                    <pre><code>second</code></pre>";
+        assert_eq!(extract_sample(html).unwrap(), "[1,[2,3]]\n-> &\n");
+        assert_eq!(extract_sample("<p>nothing</p>"), None);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let dir = root.join(DAYS_DIR);
+        let inputs = root.join("inputs");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mod.rs"), REGISTRY).unwrap();
+
+        let scaffold = create(&root, &inputs, 2, Some("1\n2\n")).unwrap();
+        assert_eq!(scaffold.module, dir.join("day02.rs"));
+        assert_eq!(scaffold.input, Some(inputs.join("day02.txt")));
+        assert!(fs::read_to_string(dir.join("mod.rs"))
+            .unwrap()
+            .contains("day02::Day02"));
+        assert_eq!(fs::read_to_string(inputs.join("day02.txt")).unwrap(), "");
+
+        assert!(matches!(
+            create(&root, &inputs, 2, None),
+            Err(Error::DayExists { day: 2, .. })
+        ));
+        assert!(matches!(
+            create(&root, &inputs, 3, None),
+            Err(Error::DayExists { day: 3, .. })
+        ));
+        assert!(!dir.join("day03.rs").exists());
+
+        fs::write(inputs.join("day04.txt"), "input").unwrap();
+        let scaffold = create(&root, &inputs, 4, None).unwrap();
+        assert_eq!(scaffold.input, None);
+        assert_eq!(
+            fs::read_to_string(inputs.join("day04.txt")).unwrap(),
+            "input"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The edges of a weighted graph, as a function from a node to the nodes one
+/// step away from it along with the cost of each step.
+///
+/// Any closure returning such pairs is one, so graphs are usually written
+/// inline at the call site.
+pub trait Neighbours<N> {
+    fn neighbours(&mut self, node: N) -> impl IntoIterator<Item = (N, usize)>;
+}
+
+impl<N, F, I> Neighbours<N> for F
+where
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    fn neighbours(&mut self, node: N) -> impl IntoIterator<Item = (N, usize)> {
+        self(node)
+    }
+}
+
+/// Turns a function listing neighbours into a graph where every step costs 1.
+pub fn unit<N, I>(mut neighbours: impl FnMut(N) -> I) -> impl Neighbours<N>
+where
+    I: IntoIterator<Item = N>,
+{
+    move |node| neighbours(node).into_iter().map(|n| (n, 1))
+}
+
+/// What a search found out: the distance to every node it reached, and the
+/// node each was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    fn new(starts: impl IntoIterator<Item = N>) -> Self {
+        Self {
+            distances: starts.into_iter().map(|start| (start, 0)).collect(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Distance from the closest start to `node`, if it was reached.
+    pub fn distance(&self, node: N) -> Option<usize> {
+        self.distances.get(&node).copied()
+    }
+
+    /// Every node reached, along with its distance.
+    pub fn iter(&self) -> impl Iterator<Item = (N, usize)> + '_ {
+        self.distances.iter().map(|(&node, &dist)| (node, dist))
+    }
+
+    /// The node `node` was reached from, or `None` for the starts and nodes
+    /// that weren't reached.
+    pub fn predecessor(&self, node: N) -> Option<N> {
+        self.predecessors.get(&node).copied()
+    }
+
+    /// A shortest path from one of the starts to `node`, both included.
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        self.distances.get(&node)?;
+        let mut path = vec![node];
+        while let Some(prev) = self.predecessor(*path.last().unwrap()) {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from every node in `starts` at once, for graphs where
+/// every step costs the same. Visits everything reachable, so it doubles as
+/// flood fill.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(starts);
+    let mut queue = search.distances.keys().copied().collect::<VecDeque<_>>();
+
+    while let Some(node) = queue.pop_front() {
+        let dist = search.distances[&node] + 1;
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next) {
+                entry.insert(dist);
+                search.predecessors.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's shortest paths from every node in `starts` at once, visiting
+/// everything reachable.
+pub fn dijkstra<N>(starts: impl IntoIterator<Item = N>, neighbours: impl Neighbours<N>) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+{
+    best_first(starts, neighbours, |_| 0, |_| false).0
+}
+
+/// A* search from `starts` to the first node satisfying `goal`, returning its
+/// distance and the path to it.
+///
+/// `heuristic` must never overestimate the distance left to a goal, or the
+/// path found may not be the shortest.
+pub fn astar<N>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl Neighbours<N>,
+    heuristic: impl FnMut(N) -> usize,
+    goal: impl FnMut(N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Copy + Eq + Hash,
+{
+    let (search, found) = best_first(starts, neighbours, heuristic, goal);
+    let found = found?;
+    Some((search.distance(found)?, search.path(found)?))
+}
+
+/// An entry of the frontier, ordered by priority alone so nodes need no
+/// ordering of their own.
+struct Frontier<N> {
+    priority: usize,
+    dist: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Frontier<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Frontier<N> {}
+
+impl<N> PartialOrd for Frontier<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Frontier<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Expands nodes by distance plus `heuristic` until one satisfies `goal` or
+/// there's nothing left, in which case everything reachable was visited.
+fn best_first<N>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl Neighbours<N>,
+    mut heuristic: impl FnMut(N) -> usize,
+    mut goal: impl FnMut(N) -> bool,
+) -> (Search<N>, Option<N>)
+where
+    N: Copy + Eq + Hash,
+{
+    let mut search = Search::new(starts);
+    let mut heap = search
+        .distances
+        .keys()
+        .map(|&node| {
+            Reverse(Frontier {
+                priority: heuristic(node),
+                dist: 0,
+                node,
+            })
+        })
+        .collect::<BinaryHeap<_>>();
+
+    while let Some(Reverse(Frontier { dist, node, .. })) = heap.pop() {
+        if search.distances[&node] < dist {
+            continue;
+        }
+        if goal(node) {
+            return (search, Some(node));
+        }
+
+        for (next, cost) in neighbours.neighbours(node) {
+            let next_dist = dist + cost;
+            if search.distance(next).is_none_or(|d| next_dist < d) {
+                search.distances.insert(next, next_dist);
+                search.predecessors.insert(next, node);
+                heap.push(Reverse(Frontier {
+                    priority: next_dist + heuristic(next),
+                    dist: next_dist,
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    (search, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -1- 1 -1- 2 -1- 3, and a shortcut 0 -5- 3 that isn't shorter.
+    fn weighted(node: u8) -> Vec<(u8, usize)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (3, 1)],
+            3 => vec![(2, 1), (0, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], |n: i32| {
+            [n - 1, n + 1].into_iter().filter(|n| n.abs() <= 3)
+        });
+        assert_eq!(search.iter().count(), 7);
+        assert_eq!(search.distance(-3), Some(3));
+        assert_eq!(search.path(2), Some(vec![0, 1, 2]));
+        assert_eq!(search.distance(4), None);
+
+        let search = bfs([-3, 3], |n: i32| {
+            [n - 1, n + 1].into_iter().filter(|n| n.abs() <= 3)
+        });
+        assert_eq!(search.distance(0), Some(3));
+        assert_eq!(search.path(-2), Some(vec![-3, -2]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra([0], weighted);
+        assert_eq!(search.distance(3), Some(3));
+        assert_eq!(search.path(3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(search.predecessor(0), None);
+
+        let search = dijkstra([0], unit(|n: u8| weighted(n).into_iter().map(|(n, _)| n)));
+        assert_eq!(search.path(3), Some(vec![0, 3]));
+    }
+
+    #[test]
+    fn test_astar() {
+        let found = astar([0], weighted, |n| 3 - n as usize, |n| n == 3);
+        assert_eq!(found, Some((3, vec![0, 1, 2, 3])));
+        assert_eq!(astar([0], weighted, |_| 0, |n| n == 7), None);
+    }
+}
@@ -0,0 +1,40 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// The `k` largest of `items` along with their positions, largest first,
+/// and earliest first among equals. Only `k` items are held at a time, so
+/// `items` can be as long as it likes.
+pub fn top_k<T: Ord>(items: impl IntoIterator<Item = T>, k: usize) -> Vec<(usize, T)> {
+    // The smallest of the kept items on top, and the latest among equals,
+    // ready to make way for a larger one.
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (i, item) in items.into_iter().enumerate() {
+        if heap.len() < k {
+            heap.push(Reverse((item, Reverse(i))));
+        } else if let Some(mut smallest) = heap.peek_mut() {
+            if item > smallest.0 .0 {
+                *smallest = Reverse((item, Reverse(i)));
+            }
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((item, Reverse(i)))| (i, item))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_k() {
+        let items = [4, 9, 1, 9, 7, 3, 7];
+        assert_eq!(top_k(items, 3), [(1, 9), (3, 9), (4, 7)]);
+        assert_eq!(top_k(items, 1), [(1, 9)]);
+        assert_eq!(top_k(items, 0), []);
+        assert_eq!(top_k(items, 10).len(), items.len());
+        assert_eq!(top_k(items, 10)[6], (2, 1));
+        assert_eq!(top_k(Vec::<u8>::new(), 2), []);
+    }
+}
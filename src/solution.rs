@@ -0,0 +1,40 @@
+use crate::{rng::Rng, Answer, ParseError};
+
+/// A puzzle solver for a single day.
+///
+/// Parsing is kept separate from the two parts so that the parsed input can
+/// be shared between them, and so that tooling can drive every day the same
+/// way regardless of what each part returns.
+pub trait Solution {
+    /// Day of the month the puzzle was released on.
+    const DAY: u8;
+    /// Example input from the puzzle text.
+    const SAMPLE: &'static str;
+
+    /// Parsed form of the puzzle input, which may borrow from the raw text.
+    type Input<'a>;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    fn part1(&self, input: &Self::Input<'_>) -> Self::Output1;
+    fn part2(&self, input: &Self::Input<'_>) -> Self::Output2;
+
+    /// A random input in the puzzle's format that both parts can solve, for
+    /// trying them out at scale. What `size` counts depends on the day, like
+    /// lines of input or the side of a grid. Days without a generator give
+    /// `None`.
+    fn generate(&self, _size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+
+    /// Parses `input` and solves part 1, panicking if it doesn't parse.
+    fn run1(&self, input: &str) -> Self::Output1 {
+        self.part1(&self.parse(input).unwrap_or_else(|e| panic!("{e}")))
+    }
+
+    /// Parses `input` and solves part 2, panicking if it doesn't parse.
+    fn run2(&self, input: &str) -> Self::Output2 {
+        self.part2(&self.parse(input).unwrap_or_else(|e| panic!("{e}")))
+    }
+}
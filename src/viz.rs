@@ -0,0 +1,426 @@
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use log::warn;
+
+use crate::grid::Pos;
+
+/// How a piece of a frame should stand out. Backends without colours ignore
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    /// Less important, like guides.
+    Dim,
+    /// A good outcome.
+    Good,
+    /// A bad outcome.
+    Bad,
+    /// Something lit up, like a pixel.
+    Lit,
+    /// A solid obstacle, like rock.
+    Solid,
+    /// Something that piled up, like sand.
+    Filled,
+    /// Empty space.
+    Empty,
+}
+
+impl Style {
+    pub const ALL: [Style; 8] = [
+        Style::Plain,
+        Style::Dim,
+        Style::Good,
+        Style::Bad,
+        Style::Lit,
+        Style::Solid,
+        Style::Filled,
+        Style::Empty,
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+/// One picture: some lines of styled text, with an optional title.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    title: Option<String>,
+    lines: Vec<Vec<Span>>,
+    animated: bool,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A frame with one character per cell of a `width` by `height` grid.
+    pub fn grid(width: usize, height: usize, mut cell: impl FnMut(Pos) -> (char, Style)) -> Self {
+        let mut frame = Self::new();
+        for y in 0..height {
+            for x in 0..width {
+                let (c, style) = cell(Pos::new(x, y));
+                frame.push(c, style);
+            }
+            frame.end_line();
+        }
+        frame
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Marks the frame as a step of an animation, which backends that can
+    /// draw it over the previous step rather than after it.
+    pub fn animated(mut self) -> Self {
+        self.animated = true;
+        self
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Adds `text` at the end of the current line.
+    pub fn push(&mut self, text: impl fmt::Display, style: Style) -> &mut Self {
+        if self.lines.is_empty() {
+            self.lines.push(Vec::new());
+        }
+        let line = self.lines.last_mut().unwrap();
+        match line.last_mut() {
+            Some(span) if span.style == style => span.text += &text.to_string(),
+            _ => line.push(Span {
+                text: text.to_string(),
+                style,
+            }),
+        }
+        self
+    }
+
+    /// Starts a new line.
+    pub fn end_line(&mut self) -> &mut Self {
+        if self.lines.is_empty() {
+            self.lines.push(Vec::new());
+        }
+        self.lines.push(Vec::new());
+        self
+    }
+
+    /// The lines of the frame, without a trailing empty one.
+    pub fn lines(&self) -> &[Vec<Span>] {
+        match self.lines.split_last() {
+            Some((last, lines)) if last.is_empty() => lines,
+            _ => &self.lines,
+        }
+    }
+
+    /// Number of lines the frame takes when printed, title included.
+    pub fn height(&self) -> usize {
+        self.lines().len() + usize::from(self.title.is_some())
+    }
+}
+
+impl fmt::Display for Frame {
+    /// The frame as plain text, without styles.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(title) = &self.title {
+            writeln!(f, "{title}")?;
+        }
+        for line in self.lines() {
+            for span in line {
+                write!(f, "{}", span.text)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Somewhere to show frames.
+pub trait Visualizer: Send {
+    fn show(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once there are no more frames, for backends that write
+    /// something out at the end.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Draws frames in a terminal with colours, animations in place.
+pub struct Ansi {
+    out: Box<dyn Write + Send>,
+    /// Pause after each step of an animation, so it can be followed.
+    delay: Duration,
+    /// Height of the last frame, if it was a step of an animation.
+    last: Option<usize>,
+}
+
+impl Ansi {
+    pub fn new(out: Box<dyn Write + Send>, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            last: None,
+        }
+    }
+
+    fn code(style: Style) -> &'static str {
+        match style {
+            Style::Plain => "0",
+            Style::Dim => "90",
+            Style::Good => "32;1",
+            Style::Bad => "31;1",
+            Style::Lit => "37;1",
+            Style::Solid => "41",
+            Style::Filled => "43",
+            Style::Empty => "40",
+        }
+    }
+}
+
+impl Visualizer for Ansi {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if let (true, Some(height)) = (frame.is_animated(), self.last) {
+            // Back to the start of the previous step, to draw over it.
+            write!(self.out, "\x1b[{height}F")?;
+        }
+
+        if let Some(title) = &frame.title {
+            writeln!(self.out, "\x1b[1m{title}\x1b[0m\x1b[K")?;
+        }
+        for line in frame.lines() {
+            for span in line {
+                write!(self.out, "\x1b[{}m{}", Self::code(span.style), span.text)?;
+            }
+            writeln!(self.out, "\x1b[0m\x1b[K")?;
+        }
+        // Clear what's left of a taller previous step.
+        write!(self.out, "\x1b[J")?;
+        self.out.flush()?;
+
+        self.last = frame.is_animated().then(|| frame.height());
+        if frame.is_animated() {
+            std::thread::sleep(self.delay);
+        }
+        Ok(())
+    }
+}
+
+/// Prints frames as plain text, one after the other.
+pub struct Plain {
+    out: Box<dyn Write + Send>,
+}
+
+impl Plain {
+    pub fn new(out: Box<dyn Write + Send>) -> Self {
+        Self { out }
+    }
+}
+
+impl Visualizer for Plain {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        writeln!(self.out, "{frame}")?;
+        self.out.flush()
+    }
+}
+
+/// Writes each frame as plain text to its own numbered file, to look at or
+/// stitch together afterwards.
+pub struct Dump {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl Dump {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, count: 0 })
+    }
+}
+
+impl Visualizer for Dump {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        self.count += 1;
+        let path = self.dir.join(format!("frame{:05}.txt", self.count));
+        fs::write(path, frame.to_string())
+    }
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static VISUALIZER: Mutex<Option<Box<dyn Visualizer>>> = Mutex::new(None);
+static EVERY: AtomicUsize = AtomicUsize::new(1);
+static STEPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Shows every frame from now on with `visualizer`.
+pub fn set(visualizer: impl Visualizer + 'static) {
+    *VISUALIZER.lock().unwrap() = Some(Box::new(visualizer));
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Shows only one in `every` steps of animations from now on.
+pub fn set_every(every: usize) {
+    EVERY.store(every.max(1), Ordering::Relaxed);
+}
+
+/// Whether frames are shown at all.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows the frame built by `frame` as a step of an animation, unless it's
+/// one of the steps skipped per [`set_every`].
+pub fn step(frame: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+
+    let step = STEPS.fetch_add(1, Ordering::Relaxed);
+    if step.is_multiple_of(EVERY.load(Ordering::Relaxed)) {
+        show(frame);
+    }
+}
+
+/// Shows the frame built by `frame`, if a visualizer was [`set`]. Until then
+/// frames aren't even built, so tests and benchmarks don't pay for them.
+///
+/// A visualizer that fails is reported once, then dropped.
+pub fn show(frame: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+
+    let mut visualizer = VISUALIZER.lock().unwrap();
+    if let Some(v) = visualizer.as_mut() {
+        if let Err(e) = v.show(&frame()) {
+            warn!("stopped showing frames: {e}");
+            *visualizer = None;
+            ENABLED.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Lets the visualizer write out whatever it kept until the end, and stops
+/// showing frames.
+pub fn finish() {
+    ENABLED.store(false, Ordering::Relaxed);
+    if let Some(mut v) = VISUALIZER.lock().unwrap().take() {
+        if let Err(e) = v.finish() {
+            warn!("couldn't finish writing frames: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// A writer whose output can be looked at after handing it over.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Shared {
+        fn take(&self) -> String {
+            String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+        }
+    }
+
+    fn frame() -> Frame {
+        Frame::grid(3, 2, |p| {
+            if p.x == p.y {
+                ('#', Style::Solid)
+            } else {
+                ('.', Style::Empty)
+            }
+        })
+        .title("step")
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = frame();
+        assert_eq!(frame.height(), 3);
+        assert_eq!(frame.lines()[1].len(), 3);
+        assert_eq!(frame.to_string(), "step\n#..\n.#.\n");
+
+        let mut frame = Frame::new();
+        frame
+            .push("a", Style::Plain)
+            .push(1, Style::Plain)
+            .end_line();
+        frame.push("b", Style::Good);
+        assert_eq!(frame.lines().len(), 2);
+        assert_eq!(frame.lines()[0][0].text, "a1");
+        assert_eq!(frame.to_string(), "a1\nb\n");
+    }
+
+    #[test]
+    fn test_plain() {
+        let out = Shared::default();
+        let mut plain = Plain::new(Box::new(out.clone()));
+        plain.show(&frame()).unwrap();
+        plain.show(&frame().animated()).unwrap();
+        assert_eq!(out.take(), "step\n#..\n.#.\n\nstep\n#..\n.#.\n\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let out = Shared::default();
+        let mut ansi = Ansi::new(Box::new(out.clone()), Duration::ZERO);
+
+        ansi.show(&frame()).unwrap();
+        let first = out.take();
+        assert!(first.contains("\x1b[41m#\x1b[40m.."));
+        assert!(!first.contains("\x1b[3F"));
+
+        ansi.show(&frame().animated()).unwrap();
+        assert!(!out.take().starts_with("\x1b[3F"));
+        ansi.show(&frame().animated()).unwrap();
+        assert!(out.take().starts_with("\x1b[3F"));
+    }
+
+    #[test]
+    fn test_dump() {
+        let dir = std::env::temp_dir().join(format!("aoc-viz-{}", std::process::id()));
+        let mut dump = Dump::new(&dir).unwrap();
+        dump.show(&frame()).unwrap();
+        dump.show(&Frame::new().title("end")).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("frame00001.txt")).unwrap(),
+            "step\n#..\n.#.\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("frame00002.txt")).unwrap(),
+            "end\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}